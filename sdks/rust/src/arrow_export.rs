@@ -0,0 +1,186 @@
+//! Apache Arrow columnar export for bulk attestation analytics.
+//!
+//! Flattens `TransformAttestation`s into an Arrow `RecordBatch` so large
+//! collections can be queried, filtered, and aggregated with DataFusion or
+//! written out as Parquet instead of being parsed one JSON document at a
+//! time. Scalar predicate fields become their own columns for fast
+//! filtering; the full attestation is additionally carried in a
+//! `payload_json` column so [`from_record_batch`] can reconstruct it exactly
+//! (including the variable-length `inputs`/`subject` lists and the
+//! freeform `parameters` map, which don't flatten cleanly into columns).
+//!
+//! Gated behind the `arrow` feature since `arrow` is a heavy, optional
+//! dependency most users of the SDK don't need.
+
+use std::sync::Arc;
+
+use arrow::array::{Float64Array, StringArray, TimestampMicrosecondArray, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow::record_batch::RecordBatch;
+
+use crate::error::{MakotoError, Result};
+use crate::types::TransformAttestation;
+
+/// Arrow schema produced by [`to_record_batch`].
+pub fn schema() -> Schema {
+    Schema::new(vec![
+        Field::new("subject_name", DataType::Utf8, true),
+        Field::new("subject_digest", DataType::Utf8, true),
+        Field::new("transform_type", DataType::Utf8, false),
+        Field::new("transform_name", DataType::Utf8, false),
+        Field::new("transform_version", DataType::Utf8, true),
+        Field::new("executor_id", DataType::Utf8, false),
+        Field::new("executor_platform", DataType::Utf8, true),
+        Field::new("records_input", DataType::UInt64, true),
+        Field::new("records_output", DataType::UInt64, true),
+        Field::new("bytes_input", DataType::UInt64, true),
+        Field::new("bytes_output", DataType::UInt64, true),
+        Field::new(
+            "started_on",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            true,
+        ),
+        Field::new(
+            "finished_on",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            true,
+        ),
+        Field::new("duration_seconds", DataType::Float64, true),
+        Field::new("payload_json", DataType::Utf8, false),
+    ])
+}
+
+/// Flatten attestations into a single Arrow `RecordBatch`.
+///
+/// Returns an empty batch (conforming to [`schema`]) if `attestations` is
+/// empty.
+pub fn to_record_batch(attestations: &[TransformAttestation]) -> Result<RecordBatch> {
+    let mut subject_name = Vec::with_capacity(attestations.len());
+    let mut subject_digest = Vec::with_capacity(attestations.len());
+    let mut transform_type = Vec::with_capacity(attestations.len());
+    let mut transform_name = Vec::with_capacity(attestations.len());
+    let mut transform_version = Vec::with_capacity(attestations.len());
+    let mut executor_id = Vec::with_capacity(attestations.len());
+    let mut executor_platform = Vec::with_capacity(attestations.len());
+    let mut records_input = Vec::with_capacity(attestations.len());
+    let mut records_output = Vec::with_capacity(attestations.len());
+    let mut bytes_input = Vec::with_capacity(attestations.len());
+    let mut bytes_output = Vec::with_capacity(attestations.len());
+    let mut started_on = Vec::with_capacity(attestations.len());
+    let mut finished_on = Vec::with_capacity(attestations.len());
+    let mut duration_seconds = Vec::with_capacity(attestations.len());
+    let mut payload_json = Vec::with_capacity(attestations.len());
+
+    for attestation in attestations {
+        let first_subject = attestation.subject.first();
+        subject_name.push(first_subject.map(|s| s.name.clone()));
+        subject_digest.push(first_subject.map(|s| s.digest.sha256.clone()));
+
+        transform_type.push(attestation.predicate.transform.transform_type.clone());
+        transform_name.push(attestation.predicate.transform.name.clone());
+        transform_version.push(attestation.predicate.transform.version.clone());
+
+        executor_id.push(attestation.predicate.executor.id.clone());
+        executor_platform.push(attestation.predicate.executor.platform.clone());
+
+        let metadata = attestation.predicate.metadata.as_ref();
+        records_input.push(metadata.and_then(|m| m.records_input));
+        records_output.push(metadata.and_then(|m| m.records_output));
+        bytes_input.push(metadata.and_then(|m| m.bytes_input));
+        bytes_output.push(metadata.and_then(|m| m.bytes_output));
+        started_on.push(metadata.and_then(|m| m.started_on).map(|t| t.timestamp_micros()));
+        finished_on.push(metadata.and_then(|m| m.finished_on).map(|t| t.timestamp_micros()));
+        duration_seconds.push(metadata.and_then(|m| m.duration_seconds));
+
+        payload_json.push(serde_json::to_string(attestation)?);
+    }
+
+    let columns: Vec<Arc<dyn arrow::array::Array>> = vec![
+        Arc::new(StringArray::from(subject_name)),
+        Arc::new(StringArray::from(subject_digest)),
+        Arc::new(StringArray::from(transform_type)),
+        Arc::new(StringArray::from(transform_name)),
+        Arc::new(StringArray::from(transform_version)),
+        Arc::new(StringArray::from(executor_id)),
+        Arc::new(StringArray::from(executor_platform)),
+        Arc::new(UInt64Array::from(records_input)),
+        Arc::new(UInt64Array::from(records_output)),
+        Arc::new(UInt64Array::from(bytes_input)),
+        Arc::new(UInt64Array::from(bytes_output)),
+        Arc::new(TimestampMicrosecondArray::from(started_on)),
+        Arc::new(TimestampMicrosecondArray::from(finished_on)),
+        Arc::new(Float64Array::from(duration_seconds)),
+        Arc::new(StringArray::from(payload_json)),
+    ];
+
+    RecordBatch::try_new(Arc::new(schema()), columns)
+        .map_err(|e| MakotoError::InvalidAttestation(format!("failed to build record batch: {}", e)))
+}
+
+/// Reconstruct the original attestations from a batch produced by
+/// [`to_record_batch`], using the `payload_json` column for full fidelity.
+pub fn from_record_batch(batch: &RecordBatch) -> Result<Vec<TransformAttestation>> {
+    let column = batch
+        .column_by_name("payload_json")
+        .ok_or_else(|| MakotoError::MissingField("payload_json".to_string()))?;
+
+    let payloads = column
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .ok_or_else(|| {
+            MakotoError::InvalidAttestation("payload_json column is not Utf8".to_string())
+        })?;
+
+    (0..payloads.len())
+        .map(|i| {
+            if payloads.is_null(i) {
+                return Err(MakotoError::MissingField(format!(
+                    "payload_json is null at row {}",
+                    i
+                )));
+            }
+            serde_json::from_str(payloads.value(i)).map_err(MakotoError::from)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::common::IsolationLevel;
+    use crate::types::transform::{Executor, InputReference, TransformDefinition};
+    use crate::types::{Digest, Subject};
+
+    fn sample() -> TransformAttestation {
+        let input = InputReference::new("dataset:input", Digest::new("a".repeat(64)));
+        let transform = TransformDefinition::new("https://makoto.dev/transforms/filter", "Filter")
+            .with_version("1.0.0");
+        let executor = Executor::new("https://expanso.io/executors/001").with_isolation(IsolationLevel::Container);
+
+        TransformAttestation::builder()
+            .subject(Subject::new("dataset:output", Digest::new("b".repeat(64))))
+            .input(input)
+            .transform(transform)
+            .executor(executor)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let attestations = vec![sample(), sample()];
+        let batch = to_record_batch(&attestations).unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+
+        let restored = from_record_batch(&batch).unwrap();
+        assert_eq!(restored, attestations);
+    }
+
+    #[test]
+    fn test_empty_batch() {
+        let batch = to_record_batch(&[]).unwrap();
+        assert_eq!(batch.num_rows(), 0);
+        assert!(from_record_batch(&batch).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,441 @@
+//! UCAN-style capability delegation for transform attestations.
+//!
+//! Models a chain of delegated authority (loosely following [UCAN](https://github.com/ucan-wg/spec)):
+//! an issuer DID grants an audience DID a capability over a resource, with
+//! an optional expiry, and that audience can further delegate (narrowing,
+//! never broadening) down a chain that must terminate at a trusted root
+//! and end at the executor that actually produced the attestation.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MakotoError, Result};
+use crate::types::transform::{InputReference, TransformPredicate};
+use crate::types::Subject;
+
+/// A decentralized identifier, e.g. `did:key:z6Mk...`.
+pub type Did = String;
+
+/// An action a capability authorizes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CapabilityAction {
+    Transform,
+}
+
+/// A single granted capability: an action over a resource, with optional
+/// caveats narrowing what the capability actually covers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capability {
+    /// Resource URI, e.g. `dataset:customer_transactions`.
+    pub resource: String,
+
+    /// Action authorized over the resource.
+    pub action: CapabilityAction,
+
+    /// Allowed `transform.type` prefixes; empty means unrestricted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_transform_prefixes: Vec<String>,
+}
+
+impl Capability {
+    /// Create a new capability.
+    pub fn new(resource: impl Into<String>, action: CapabilityAction) -> Self {
+        Self {
+            resource: resource.into(),
+            action,
+            allowed_transform_prefixes: Vec::new(),
+        }
+    }
+
+    /// Restrict the capability to transform types with one of these prefixes.
+    pub fn with_allowed_transform_prefixes(mut self, prefixes: Vec<String>) -> Self {
+        self.allowed_transform_prefixes = prefixes;
+        self
+    }
+
+    /// Whether `self` is at least as narrow as `parent` (never broader).
+    fn narrows(&self, parent: &Capability) -> bool {
+        if self.resource != parent.resource || self.action != parent.action {
+            return false;
+        }
+
+        if parent.allowed_transform_prefixes.is_empty() {
+            return true;
+        }
+
+        !self.allowed_transform_prefixes.is_empty()
+            && self
+                .allowed_transform_prefixes
+                .iter()
+                .all(|p| parent.allowed_transform_prefixes.iter().any(|pp| p.starts_with(pp.as_str())))
+    }
+
+    /// Whether this capability covers the given dataset name and transform type.
+    fn covers(&self, dataset: &str, transform_type: &str) -> bool {
+        self.resource == dataset
+            && (self.allowed_transform_prefixes.is_empty()
+                || self
+                    .allowed_transform_prefixes
+                    .iter()
+                    .any(|p| transform_type.starts_with(p.as_str())))
+    }
+}
+
+/// A single signed link in a delegation chain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UcanToken {
+    /// DID of the entity granting the capability.
+    pub issuer: Did,
+
+    /// DID of the entity receiving the capability.
+    pub audience: Did,
+
+    /// Capabilities granted by this link.
+    pub capabilities: Vec<Capability>,
+
+    /// When this grant expires; a root issuer can't be held to an
+    /// authorization that was only ever meant to be temporary. `None` means
+    /// the grant doesn't expire.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// Base64url-encoded signature over the token's canonical contents,
+    /// verified by a `DidVerifier`.
+    pub signature: String,
+}
+
+impl UcanToken {
+    /// Create a new (unsigned) token.
+    pub fn new(issuer: impl Into<String>, audience: impl Into<String>, capabilities: Vec<Capability>) -> Self {
+        Self {
+            issuer: issuer.into(),
+            audience: audience.into(),
+            capabilities,
+            expires_at: None,
+            signature: String::new(),
+        }
+    }
+
+    /// Set when this grant expires.
+    pub fn with_expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Attach a signature produced out-of-band.
+    pub fn with_signature(mut self, signature: impl Into<String>) -> Self {
+        self.signature = signature.into();
+        self
+    }
+
+    /// Bytes covered by the signature: `issuer|audience|capabilities-json|expires_at`.
+    fn signing_bytes(&self) -> Result<Vec<u8>> {
+        let caps_json = serde_json::to_string(&self.capabilities)?;
+        let expires = self
+            .expires_at
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default();
+        Ok(format!("{}|{}|{}|{}", self.issuer, self.audience, caps_json, expires).into_bytes())
+    }
+}
+
+/// An ordered delegation chain, from the trusted root to the executor.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UcanChain {
+    /// Tokens in delegation order: `tokens[0].issuer` should be a trusted
+    /// root, and `tokens.last().audience` should be the attesting executor.
+    pub tokens: Vec<UcanToken>,
+}
+
+impl UcanChain {
+    /// Create a chain from an ordered list of tokens.
+    pub fn new(tokens: Vec<UcanToken>) -> Self {
+        Self { tokens }
+    }
+}
+
+/// Verifies the signature on a single UCAN token for a given issuer DID.
+pub trait DidVerifier {
+    /// Verify that `signature` over `message` was produced by `issuer`.
+    fn verify(&self, issuer: &Did, message: &[u8], signature: &str) -> Result<bool>;
+}
+
+/// Validate a `UcanChain` against a set of trusted root DIDs and the
+/// attestation it authorizes.
+///
+/// Checks:
+/// - the chain is non-empty and its first issuer is a trusted root,
+/// - each link's audience equals the next link's issuer,
+/// - the final audience matches `executor_id`,
+/// - capabilities only narrow down the chain,
+/// - every subject/input dataset is covered by a granted capability,
+/// - every token's signature verifies against its issuer,
+/// - no token has expired as of `reference_time`.
+pub fn validate_authorization(
+    chain: &UcanChain,
+    predicate: &TransformPredicate,
+    subjects: &[Subject],
+    executor_id: &str,
+    trusted_roots: &[Did],
+    verifier: &dyn DidVerifier,
+    reference_time: DateTime<Utc>,
+) -> Result<()> {
+    let Some(first) = chain.tokens.first() else {
+        return Err(MakotoError::InvalidAttestation(
+            "authorization chain is empty".to_string(),
+        ));
+    };
+
+    if !trusted_roots.contains(&first.issuer) {
+        return Err(MakotoError::InvalidAttestation(format!(
+            "authorization chain root issuer '{}' is not a trusted root",
+            first.issuer
+        )));
+    }
+
+    for window in chain.tokens.windows(2) {
+        let (link, next) = (&window[0], &window[1]);
+        if link.audience != next.issuer {
+            return Err(MakotoError::InvalidAttestation(format!(
+                "authorization chain broken: '{}' delegated to '{}' but next link is issued by '{}'",
+                link.issuer, link.audience, next.issuer
+            )));
+        }
+    }
+
+    let last = chain.tokens.last().expect("chain is non-empty");
+    if last.audience != executor_id {
+        return Err(MakotoError::InvalidAttestation(format!(
+            "authorization chain audience '{}' does not match executor '{}'",
+            last.audience, executor_id
+        )));
+    }
+
+    for token in &chain.tokens {
+        let verified = verifier
+            .verify(&token.issuer, &token.signing_bytes()?, &token.signature)
+            .unwrap_or(false);
+        if !verified {
+            return Err(MakotoError::InvalidAttestation(format!(
+                "invalid signature on authorization token from '{}'",
+                token.issuer
+            )));
+        }
+
+        if let Some(expires_at) = token.expires_at {
+            if reference_time > expires_at {
+                return Err(MakotoError::InvalidAttestation(format!(
+                    "authorization token from '{}' to '{}' expired at {}",
+                    token.issuer, token.audience, expires_at
+                )));
+            }
+        }
+    }
+
+    for window in chain.tokens.windows(2) {
+        let (parent, child) = (&window[0], &window[1]);
+        for child_cap in &child.capabilities {
+            let narrows_some_parent_cap = parent
+                .capabilities
+                .iter()
+                .any(|parent_cap| child_cap.narrows(parent_cap));
+            if !narrows_some_parent_cap {
+                return Err(MakotoError::InvalidAttestation(format!(
+                    "capability over '{}' broadens its parent grant",
+                    child_cap.resource
+                )));
+            }
+        }
+    }
+
+    let transform_type = &predicate.transform.transform_type;
+    let granted: Vec<&Capability> = last.capabilities.iter().collect();
+
+    let datasets = subjects
+        .iter()
+        .map(|s| s.name.as_str())
+        .chain(predicate.inputs.iter().map(|i: &InputReference| i.name.as_str()));
+
+    for dataset in datasets {
+        if !granted.iter().any(|cap| cap.covers(dataset, transform_type)) {
+            return Err(MakotoError::InvalidAttestation(format!(
+                "no granted capability covers dataset '{}'",
+                dataset
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct AlwaysValid;
+
+    impl DidVerifier for AlwaysValid {
+        fn verify(&self, _issuer: &Did, _message: &[u8], signature: &str) -> Result<bool> {
+            Ok(signature == "valid")
+        }
+    }
+
+    fn predicate_for(dataset: &str, transform_type: &str) -> (TransformPredicate, Vec<Subject>) {
+        use crate::types::common::IsolationLevel;
+        use crate::types::transform::{Executor, TransformDefinition};
+        use crate::types::Digest;
+
+        let predicate = TransformPredicate {
+            inputs: vec![InputReference::new(dataset, Digest::new("a".repeat(64)))],
+            transform: TransformDefinition::new(transform_type, "test"),
+            executor: Executor::new("did:key:executor").with_isolation(IsolationLevel::Process),
+            metadata: None,
+            verification: None,
+            authorization: None,
+        };
+        let subjects = vec![Subject::new("dataset:output", Digest::new("b".repeat(64)))];
+        (predicate, subjects)
+    }
+
+    #[test]
+    fn test_valid_chain_passes() {
+        let (predicate, subjects) = predicate_for("dataset:input", "https://makoto.dev/transforms/filter");
+
+        let root_grant = Capability::new("dataset:input", CapabilityAction::Transform);
+        let output_grant = Capability::new("dataset:output", CapabilityAction::Transform);
+
+        let chain = UcanChain::new(vec![UcanToken::new(
+            "did:key:root",
+            "did:key:executor",
+            vec![root_grant, output_grant],
+        )
+        .with_signature("valid")]);
+
+        let result = validate_authorization(
+            &chain,
+            &predicate,
+            &subjects,
+            "did:key:executor",
+            &["did:key:root".to_string()],
+            &AlwaysValid,
+            Utc::now(),
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_untrusted_root_rejected() {
+        let (predicate, subjects) = predicate_for("dataset:input", "https://makoto.dev/transforms/filter");
+
+        let chain = UcanChain::new(vec![UcanToken::new(
+            "did:key:stranger",
+            "did:key:executor",
+            vec![
+                Capability::new("dataset:input", CapabilityAction::Transform),
+                Capability::new("dataset:output", CapabilityAction::Transform),
+            ],
+        )
+        .with_signature("valid")]);
+
+        let result = validate_authorization(
+            &chain,
+            &predicate,
+            &subjects,
+            "did:key:executor",
+            &["did:key:root".to_string()],
+            &AlwaysValid,
+            Utc::now(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_broadening_capability_rejected() {
+        let (predicate, subjects) = predicate_for("dataset:input", "https://makoto.dev/transforms/filter");
+
+        let narrow = Capability::new("dataset:input", CapabilityAction::Transform)
+            .with_allowed_transform_prefixes(vec!["https://makoto.dev/transforms/filter".to_string()]);
+
+        let chain = UcanChain::new(vec![
+            UcanToken::new("did:key:root", "did:key:mid", vec![narrow]).with_signature("valid"),
+            UcanToken::new(
+                "did:key:mid",
+                "did:key:executor",
+                vec![
+                    Capability::new("dataset:input", CapabilityAction::Transform),
+                    Capability::new("dataset:output", CapabilityAction::Transform),
+                ],
+            )
+            .with_signature("valid"),
+        ]);
+
+        let result = validate_authorization(
+            &chain,
+            &predicate,
+            &subjects,
+            "did:key:executor",
+            &["did:key:root".to_string()],
+            &AlwaysValid,
+            Utc::now(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_uncovered_dataset_rejected() {
+        let (predicate, subjects) = predicate_for("dataset:input", "https://makoto.dev/transforms/filter");
+
+        let chain = UcanChain::new(vec![UcanToken::new(
+            "did:key:root",
+            "did:key:executor",
+            vec![Capability::new("dataset:input", CapabilityAction::Transform)],
+        )
+        .with_signature("valid")]);
+
+        let result = validate_authorization(
+            &chain,
+            &predicate,
+            &subjects,
+            "did:key:executor",
+            &["did:key:root".to_string()],
+            &AlwaysValid,
+            Utc::now(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_expired_token_rejected() {
+        let (predicate, subjects) = predicate_for("dataset:input", "https://makoto.dev/transforms/filter");
+
+        let chain = UcanChain::new(vec![UcanToken::new(
+            "did:key:root",
+            "did:key:executor",
+            vec![
+                Capability::new("dataset:input", CapabilityAction::Transform),
+                Capability::new("dataset:output", CapabilityAction::Transform),
+            ],
+        )
+        .with_expires_at(Utc::now() - chrono::Duration::days(1))
+        .with_signature("valid")]);
+
+        let result = validate_authorization(
+            &chain,
+            &predicate,
+            &subjects,
+            "did:key:executor",
+            &["did:key:root".to_string()],
+            &AlwaysValid,
+            Utc::now(),
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,114 @@
+//! Batch verification of large `SignedAttestation` sets — for chain/window
+//! verification over thousands of envelopes, where per-signature dispatch
+//! (not the ECDSA math itself) dominates runtime.
+//!
+//! [`verify_batch`] checks each `(envelope, keyring)` pair independently and
+//! reports one result per pair; an invalid signature in one pair does not
+//! stop the remaining pairs from being checked, and there is no grouped
+//! path that stops at the first failure. It does *not* do true cryptographic batch
+//! verification (combining several ECDSA signatures into one multi-scalar
+//! multiplication via a random linear combination) — that needs raw curve
+//! arithmetic this SDK doesn't otherwise expose, and a hand-rolled version
+//! would be a bigger risk than the runtime it might save, since every
+//! signature here is already a cheap single P-256 verify.
+//!
+//! Gated behind the `parallel` feature since `rayon`'s thread pool is an
+//! optional dependency — `wasm32` targets have no threads to pool, so
+//! callers building for wasm should disable it and fall back to the
+//! sequential path, which this module always provides.
+
+use crate::error::Result;
+use crate::signing::{MakotoKeyring, SignedAttestation};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Verify each `(envelope, keyring)` pair in `items`, returning one `bool`
+/// per pair in the same order (`true` iff some key in that pair's keyring
+/// produced a valid signature — see
+/// [`SignedAttestation::verify_with_keyring`]).
+///
+/// Pairs are verified independently of one another: a signature that does
+/// not verify yields `false` for that pair only. An error returned by
+/// `verify_with_keyring` for any pair (a malformed payload or signature
+/// encoding, an unsupported algorithm, or a key outside its validity
+/// window) fails the whole call.
+///
+/// With the `parallel` feature enabled, pairs are distributed across
+/// rayon's global thread pool; otherwise they're checked sequentially in
+/// order. Either way, each individual pair's signatures are checked in
+/// declaration order and stop at the first one that verifies — there's no
+/// reason to keep trying other signatures on an envelope once one of them
+/// has already proven it.
+pub fn verify_batch(items: &[(&SignedAttestation, &MakotoKeyring)]) -> Result<Vec<bool>> {
+    #[cfg(feature = "parallel")]
+    {
+        items
+            .par_iter()
+            .map(|(signed, keyring)| verify_one(signed, keyring))
+            .collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        items
+            .iter()
+            .map(|(signed, keyring)| verify_one(signed, keyring))
+            .collect()
+    }
+}
+
+fn verify_one(signed: &SignedAttestation, keyring: &MakotoKeyring) -> Result<bool> {
+    Ok(signed.verify_with_keyring(keyring)?.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::MakotoSigner;
+    use crate::types::{Digest, OriginAttestation, Subject};
+    use crate::types::common::{CollectionMethod, SourceType};
+    use crate::types::origin::{Collector, Origin};
+    use chrono::Utc;
+
+    fn sample_attestation() -> OriginAttestation {
+        OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(Origin::new(
+                "https://api.example.com/data",
+                SourceType::Api,
+                CollectionMethod::Pull,
+                Utc::now(),
+            ))
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_batch_reports_valid_and_invalid_pairs() {
+        let good_signer = MakotoSigner::generate();
+        let bad_signer = MakotoSigner::generate();
+        let attestation = sample_attestation();
+
+        let good_signed = SignedAttestation::sign(&attestation, &good_signer).unwrap();
+        let bad_signed = SignedAttestation::sign(&attestation, &bad_signer).unwrap();
+
+        let mut good_keyring = MakotoKeyring::new();
+        good_keyring.add(good_signer.verifying_key());
+
+        let mut wrong_keyring = MakotoKeyring::new();
+        wrong_keyring.add(MakotoSigner::generate().verifying_key());
+
+        let items = vec![(&good_signed, &good_keyring), (&bad_signed, &wrong_keyring)];
+        let results = verify_batch(&items).unwrap();
+
+        assert_eq!(results, vec![true, false]);
+    }
+
+    #[test]
+    fn test_verify_batch_handles_empty_input() {
+        let results = verify_batch(&[]).unwrap();
+        assert!(results.is_empty());
+    }
+}
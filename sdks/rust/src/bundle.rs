@@ -0,0 +1,519 @@
+//! A self-contained, single-document alternative to juggling a
+//! [`SignedAttestation`], a verifier key, a certificate chain, and a
+//! transparency-log inclusion proof as separate arguments — following
+//! Sigstore's "bundle" format.
+//!
+//! [`MakotoBundle`] carries whatever layers a producer attached (the
+//! signature is required; the certificate chain and log inclusion proof
+//! are optional), and [`verify_bundle`] runs every layer that's present,
+//! reporting the highest [`MakotoLevel`] actually achieved rather than
+//! requiring all of them up front.
+//!
+//! [`keyless_sign`] drives the full Sigstore-style keyless signing flow
+//! that produces one of these bundles end to end: an ephemeral key, a
+//! [`crate::keyless::CertificateAuthority`] binding it to an OIDC identity,
+//! and a [`TransparencyLogClient`] recording the resulting signature.
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::keyless::{self, Certificate, CertificateAuthority, IdentityPolicy, TrustRoots};
+use crate::signing::{InclusionProof, LogCheckpoint, MakotoSigner, MakotoVerifier, SignedAttestation};
+use crate::types::MakotoLevel;
+use crate::verification::{verify_attestation_json, verify_transparency_inclusion, VerificationResult};
+
+/// Media type [`MakotoBundle`] documents are identified by, so
+/// [`crate::verification::detect_attestation_type`] can recognize one
+/// alongside plain attestations and signed envelopes.
+pub const BUNDLE_MEDIA_TYPE: &str = "application/vnd.makoto.bundle+json";
+
+/// The bundle format version [`verify_bundle`] understands.
+pub const BUNDLE_VERSION: &str = "1";
+
+/// The log inclusion layer of a [`MakotoBundle`]: the proof plus the
+/// checkpoint it must be checked against.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleInclusion {
+    /// Proof that the bundle's payload is included in the log.
+    pub proof: InclusionProof,
+    /// The log checkpoint the proof is checked against.
+    pub checkpoint: LogCheckpoint,
+}
+
+/// A self-contained, single-document verifiable unit: the signed
+/// attestation envelope, plus whichever optional trust layers (keyless
+/// certificate chain, transparency-log inclusion) were attached when it
+/// was produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MakotoBundle {
+    /// Always [`BUNDLE_MEDIA_TYPE`]; lets a generic parser tell a bundle
+    /// apart from a bare attestation or signed envelope.
+    pub media_type: String,
+
+    /// Always [`BUNDLE_VERSION`].
+    pub bundle_version: String,
+
+    /// The signed attestation envelope.
+    pub signed: SignedAttestation,
+
+    /// Keyless identity certificate chain, if the envelope was signed with
+    /// an ephemeral Fulcio-style key rather than a pre-shared one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert_chain: Option<Vec<Certificate>>,
+
+    /// Transparency-log inclusion proof, if the attestation was logged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inclusion: Option<BundleInclusion>,
+}
+
+impl MakotoBundle {
+    /// Wrap a signed envelope as a bundle with no optional layers attached.
+    /// Add them with [`Self::with_cert_chain`]/[`Self::with_inclusion`].
+    pub fn new(signed: SignedAttestation) -> Self {
+        Self {
+            media_type: BUNDLE_MEDIA_TYPE.to_string(),
+            bundle_version: BUNDLE_VERSION.to_string(),
+            signed,
+            cert_chain: None,
+            inclusion: None,
+        }
+    }
+
+    /// Attach a keyless identity certificate chain.
+    pub fn with_cert_chain(mut self, cert_chain: Vec<Certificate>) -> Self {
+        self.cert_chain = Some(cert_chain);
+        self
+    }
+
+    /// Attach a transparency-log inclusion proof.
+    pub fn with_inclusion(mut self, proof: InclusionProof, checkpoint: LogCheckpoint) -> Self {
+        self.inclusion = Some(BundleInclusion { proof, checkpoint });
+        self
+    }
+}
+
+/// Trust material [`verify_bundle`] needs to check whichever layers a
+/// [`MakotoBundle`] actually carries.
+#[derive(Debug, Clone)]
+pub struct TrustConfig {
+    /// Key to check the envelope's signature against, used when the bundle
+    /// carries no certificate chain.
+    pub verifier: MakotoVerifier,
+    /// Roots a certificate chain must terminate at, if the bundle carries
+    /// one.
+    pub cert_roots: Option<TrustRoots>,
+    /// Identity the certificate chain must resolve to, if the bundle
+    /// carries one.
+    pub identity_policy: Option<IdentityPolicy>,
+    /// Key to check a log checkpoint's signature against, if the bundle
+    /// carries an inclusion proof.
+    pub log_verifier: Option<MakotoVerifier>,
+}
+
+impl TrustConfig {
+    /// A config that only checks signatures against a pre-shared key; no
+    /// keyless or transparency-log layers.
+    pub fn new(verifier: MakotoVerifier) -> Self {
+        Self {
+            verifier,
+            cert_roots: None,
+            identity_policy: None,
+            log_verifier: None,
+        }
+    }
+
+    /// Enable verifying a bundle's keyless certificate chain.
+    pub fn with_keyless(mut self, cert_roots: TrustRoots, identity_policy: IdentityPolicy) -> Self {
+        self.cert_roots = Some(cert_roots);
+        self.identity_policy = Some(identity_policy);
+        self
+    }
+
+    /// Enable verifying a bundle's transparency-log inclusion proof.
+    pub fn with_log_verifier(mut self, log_verifier: MakotoVerifier) -> Self {
+        self.log_verifier = Some(log_verifier);
+        self
+    }
+}
+
+/// Verify every layer `bundle` carries against `trust`, reporting the
+/// highest [`MakotoLevel`] actually achieved: L1 (structure) → L2
+/// (signature, by pre-shared key or keyless certificate chain) → L3
+/// (transparency-log inclusion).
+///
+/// Short-circuits to a failing result on the first hard failure in a layer
+/// the bundle actually carries. A layer the bundle doesn't carry (no cert
+/// chain, no inclusion proof) doesn't fail verification — it's recorded as
+/// a warning. Without a cert chain the signature is checked against
+/// `trust.verifier` instead; without an inclusion proof the achieved level
+/// stops at L2.
+pub fn verify_bundle(bundle: &MakotoBundle, trust: &TrustConfig) -> VerificationResult {
+    let payload_bytes = match BASE64.decode(&bundle.signed.payload) {
+        Ok(bytes) => bytes,
+        Err(e) => return VerificationResult::fail(format!("invalid bundle payload base64: {e}")),
+    };
+    let payload_json = match String::from_utf8(payload_bytes.clone()) {
+        Ok(json) => json,
+        Err(e) => return VerificationResult::fail(format!("bundle payload is not valid UTF-8: {e}")),
+    };
+
+    let structure = verify_attestation_json(&payload_json);
+    if !structure.valid {
+        return structure;
+    }
+
+    let mut achieved = MakotoLevel::L1;
+    let mut messages = structure.messages;
+    let mut warnings = structure.warnings;
+
+    match &bundle.cert_chain {
+        Some(cert_chain) => {
+            let (cert_roots, identity_policy) = match (&trust.cert_roots, &trust.identity_policy) {
+                (Some(roots), Some(policy)) => (roots, policy),
+                _ => {
+                    return VerificationResult::fail(
+                        "bundle has a certificate chain but TrustConfig has no cert_roots/identity_policy configured",
+                    )
+                }
+            };
+
+            let result = keyless::verify_keyless(&bundle.signed, cert_chain, cert_roots, identity_policy);
+            if !result.valid {
+                return result;
+            }
+            achieved = result.level.unwrap_or(achieved);
+            messages.extend(result.messages);
+            warnings.extend(result.warnings);
+        }
+        None => match bundle.signed.verify(&trust.verifier) {
+            Ok(true) => {
+                achieved = MakotoLevel::L2;
+                messages.push(format!(
+                    "Signature verified for key: {}",
+                    trust.verifier.key_id()
+                ));
+                warnings.push(
+                    "bundle has no certificate chain; signature checked against the pre-shared key"
+                        .to_string(),
+                );
+            }
+            Ok(false) => return VerificationResult::fail("Signature verification failed"),
+            Err(e) => return VerificationResult::fail(format!("Signature error: {e}")),
+        },
+    }
+
+    match &bundle.inclusion {
+        Some(inclusion) => match &trust.log_verifier {
+            Some(log_verifier) => {
+                let result = verify_transparency_inclusion(
+                    &payload_bytes,
+                    &inclusion.proof,
+                    &inclusion.checkpoint,
+                    log_verifier,
+                );
+                if !result.valid {
+                    return result;
+                }
+
+                // A keyless bundle's certificate is ephemeral and typically
+                // expired by verification time, so its validity has to be
+                // checked against the log's integrated timestamp rather
+                // than the verifier's current clock.
+                if let Some(cert_chain) = &bundle.cert_chain {
+                    match (cert_chain.first(), inclusion.proof.integrated_time) {
+                        (Some(leaf), Some(integrated_time)) => {
+                            if !leaf.is_valid_at(integrated_time) {
+                                return VerificationResult::fail(format!(
+                                    "leaf certificate was not valid at the log's integrated time {}",
+                                    integrated_time
+                                ));
+                            }
+                            messages.push(format!(
+                                "Certificate validity confirmed at integrated time {}",
+                                integrated_time
+                            ));
+                        }
+                        (Some(_), None) => warnings.push(
+                            "inclusion proof has no integrated_time; skipped checking \
+                             certificate validity at signing time"
+                                .to_string(),
+                        ),
+                        (None, _) => {}
+                    }
+                }
+
+                achieved = MakotoLevel::L3;
+                messages.extend(result.messages);
+            }
+            None => warnings.push(
+                "bundle has a transparency-log inclusion proof but TrustConfig has no \
+                 log_verifier configured; skipped"
+                    .to_string(),
+            ),
+        },
+        None => warnings.push(
+            "bundle has no transparency-log inclusion proof; stopped at the achieved level"
+                .to_string(),
+        ),
+    }
+
+    let mut result = VerificationResult::pass(achieved);
+    result.messages = messages;
+    result.warnings = warnings;
+    result
+}
+
+/// An append-only transparency log that a signed envelope can be submitted
+/// to — the keyless signing flow's "Rekor" role. Injectable so private
+/// deployments can point at their own log; this SDK has no HTTP client
+/// dependency, so only the extension point is defined here, not a concrete
+/// implementation that calls a real log's API over the network.
+pub trait TransparencyLogClient {
+    /// Submit a signed envelope for logging and get back an inclusion proof
+    /// plus the checkpoint it was checked against.
+    fn submit(&self, signed: &SignedAttestation) -> Result<(InclusionProof, LogCheckpoint)>;
+}
+
+/// Run the full keyless signing flow (Sigstore-style): generate an
+/// ephemeral signing key, bind it to `oidc_token`'s identity via `ca`, sign
+/// `attestation` with the ephemeral key, submit the envelope to `log`, and
+/// package the result as a self-contained [`MakotoBundle`] ready for
+/// [`verify_bundle`].
+///
+/// The ephemeral key is discarded once this returns — nothing about the
+/// bundle depends on it being kept around, by design: a keyless signer's
+/// trust comes from the certificate and log entry, not from protecting a
+/// long-lived key.
+pub fn keyless_sign<T: Serialize>(
+    attestation: &T,
+    oidc_token: &str,
+    ca: &dyn CertificateAuthority,
+    log: &dyn TransparencyLogClient,
+) -> Result<MakotoBundle> {
+    let ephemeral = MakotoSigner::generate();
+    let cert = ca.issue_certificate(oidc_token, &ephemeral.public_key_bytes())?;
+    let signed = SignedAttestation::sign(attestation, &ephemeral)?;
+    let (proof, checkpoint) = log.submit(&signed)?;
+
+    Ok(MakotoBundle::new(signed)
+        .with_cert_chain(vec![cert])
+        .with_inclusion(proof, checkpoint))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::MakotoSigner;
+    use crate::types::common::{CollectionMethod, SourceType};
+    use crate::types::origin::{Collector, Origin};
+    use crate::types::{Digest, OriginAttestation, Subject};
+    use chrono::Utc;
+
+    fn signed_origin(signer: &MakotoSigner) -> SignedAttestation {
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let collector = Collector::new("https://example.com/collector/001");
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(collector)
+            .build()
+            .unwrap();
+
+        SignedAttestation::sign(&attestation, signer).unwrap()
+    }
+
+    #[test]
+    fn test_verify_bundle_with_only_signature_reaches_l2() {
+        let signer = MakotoSigner::generate();
+        let bundle = MakotoBundle::new(signed_origin(&signer));
+        let trust = TrustConfig::new(signer.verifying_key());
+
+        let result = verify_bundle(&bundle, &trust);
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L2));
+        assert!(!result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_verify_bundle_rejects_wrong_key() {
+        let signer = MakotoSigner::generate();
+        let other = MakotoSigner::generate();
+        let bundle = MakotoBundle::new(signed_origin(&signer));
+        let trust = TrustConfig::new(other.verifying_key());
+
+        let result = verify_bundle(&bundle, &trust);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_bundle_with_full_chain_reaches_l3() {
+        let signer = MakotoSigner::generate();
+        let log_signer = MakotoSigner::generate();
+
+        let signed = signed_origin(&signer);
+        let payload_bytes = BASE64.decode(&signed.payload).unwrap();
+
+        let leaf_hash = crate::hash::make_hasher(
+            crate::types::HashAlgorithm::Sha256,
+            crate::hash::HashMode::Rfc6962,
+        )
+        .unwrap()
+        .hash_leaf(&payload_bytes);
+        let root = hex::encode(leaf_hash);
+
+        let checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 1, root, &log_signer).unwrap();
+        let proof = InclusionProof::new(0, 1, vec![]);
+
+        let bundle = MakotoBundle::new(signed).with_inclusion(proof, checkpoint);
+        let trust = TrustConfig::new(signer.verifying_key()).with_log_verifier(log_signer.verifying_key());
+
+        let result = verify_bundle(&bundle, &trust);
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L3));
+    }
+
+    #[test]
+    fn test_verify_bundle_without_inclusion_warns_and_stops_at_l2() {
+        let signer = MakotoSigner::generate();
+        let bundle = MakotoBundle::new(signed_origin(&signer));
+        let trust = TrustConfig::new(signer.verifying_key());
+
+        let result = verify_bundle(&bundle, &trust);
+        assert_eq!(result.level, Some(MakotoLevel::L2));
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("no transparency-log inclusion proof")));
+    }
+
+    struct FakeCa<'a> {
+        issuer_signer: &'a MakotoSigner,
+    }
+
+    impl CertificateAuthority for FakeCa<'_> {
+        fn issue_certificate(&self, oidc_token: &str, public_key: &[u8]) -> Result<Certificate> {
+            let now = Utc::now();
+            Certificate::issue(
+                public_key.to_vec(),
+                format!("mailto:{oidc_token}"),
+                "fake-ca",
+                now - chrono::Duration::minutes(1),
+                now + chrono::Duration::minutes(10),
+                self.issuer_signer,
+            )
+        }
+    }
+
+    struct FakeLog<'a> {
+        log_signer: &'a MakotoSigner,
+    }
+
+    impl TransparencyLogClient for FakeLog<'_> {
+        fn submit(&self, signed: &SignedAttestation) -> Result<(InclusionProof, LogCheckpoint)> {
+            let payload_bytes = BASE64.decode(&signed.payload).unwrap();
+            let leaf_hash = crate::hash::make_hasher(
+                crate::types::HashAlgorithm::Sha256,
+                crate::hash::HashMode::Rfc6962,
+            )
+            .unwrap()
+            .hash_leaf(&payload_bytes);
+            let root = hex::encode(leaf_hash);
+
+            let checkpoint =
+                LogCheckpoint::sign("https://log.example.com", 1, root, self.log_signer)?;
+            let proof = InclusionProof::new(0, 1, vec![]).with_integrated_time(Utc::now());
+            Ok((proof, checkpoint))
+        }
+    }
+
+    #[test]
+    fn test_keyless_sign_produces_bundle_verifiable_at_l3() {
+        let ca_signer = MakotoSigner::generate();
+        let log_signer = MakotoSigner::generate();
+        let ca = FakeCa {
+            issuer_signer: &ca_signer,
+        };
+        let log = FakeLog {
+            log_signer: &log_signer,
+        };
+
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let collector = Collector::new("https://example.com/collector/001");
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(collector)
+            .build()
+            .unwrap();
+
+        let bundle = keyless_sign(&attestation, "ci@example.com", &ca, &log).unwrap();
+
+        let roots = TrustRoots::new().with_root("fake-ca", ca_signer.verifying_key());
+        let identity_policy = IdentityPolicy::new("*@example.com", "fake-ca");
+        let trust = TrustConfig::new(ca_signer.verifying_key())
+            .with_keyless(roots, identity_policy)
+            .with_log_verifier(log_signer.verifying_key());
+
+        let result = verify_bundle(&bundle, &trust);
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L3));
+    }
+
+    #[test]
+    fn test_verify_bundle_rejects_cert_expired_before_integrated_time() {
+        let ca_signer = MakotoSigner::generate();
+        let leaf_signer = MakotoSigner::generate();
+        let log_signer = MakotoSigner::generate();
+
+        // Certificate already expired by the time it gets "logged".
+        let now = Utc::now();
+        let cert = Certificate::issue(
+            leaf_signer.public_key_bytes(),
+            "mailto:ci@example.com",
+            "fake-ca",
+            now - chrono::Duration::minutes(10),
+            now - chrono::Duration::minutes(5),
+            &ca_signer,
+        )
+        .unwrap();
+
+        let signed = signed_origin(&leaf_signer);
+        let payload_bytes = BASE64.decode(&signed.payload).unwrap();
+        let leaf_hash = crate::hash::make_hasher(
+            crate::types::HashAlgorithm::Sha256,
+            crate::hash::HashMode::Rfc6962,
+        )
+        .unwrap()
+        .hash_leaf(&payload_bytes);
+        let root = hex::encode(leaf_hash);
+        let checkpoint = LogCheckpoint::sign("https://log.example.com", 1, root, &log_signer).unwrap();
+        let proof = InclusionProof::new(0, 1, vec![]).with_integrated_time(now);
+
+        let bundle = MakotoBundle::new(signed)
+            .with_cert_chain(vec![cert])
+            .with_inclusion(proof, checkpoint);
+
+        let roots = TrustRoots::new().with_root("fake-ca", ca_signer.verifying_key());
+        let identity_policy = IdentityPolicy::new("*@example.com", "fake-ca");
+        let trust = TrustConfig::new(ca_signer.verifying_key())
+            .with_keyless(roots, identity_policy)
+            .with_log_verifier(log_signer.verifying_key());
+
+        let result = verify_bundle(&bundle, &trust);
+        assert!(!result.valid);
+    }
+}
@@ -0,0 +1,260 @@
+//! Verifies that a sequence of [`StreamWindowAttestation`]s forms an
+//! unbroken, tamper-evident chain via `ChainDescriptor`.
+//!
+//! [`crate::verification::verify_stream_window_structure`] only checks a
+//! single window's own chain fields are well-formed; [`verify_chain`] walks
+//! an ordered slice of windows and checks the sequencing invariants
+//! `ChainDescriptor` is supposed to encode end to end — genesis, root
+//! continuity, window-id continuity, monotonic `chain_length`, and a
+//! constant `genesis_window_id`. It enumerates every break it finds (gap,
+//! fork, root mismatch, reordered window) with the offending window's
+//! index, so an operator can localize exactly where a stream's integrity
+//! record was truncated or spliced, rather than getting a single boolean.
+
+use crate::types::StreamWindowAttestation;
+
+/// What kind of sequencing invariant [`verify_chain`] found broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainBreakKind {
+    /// The first window's `ChainDescriptor` isn't a genesis link
+    /// (`genesis_window_id` set and `chain_length == 1`).
+    MissingGenesis,
+    /// A window has no `ChainDescriptor` at all.
+    MissingChain,
+    /// `previous_merkle_root` doesn't match the prior window's
+    /// `integrity.merkle_tree.root`.
+    RootMismatch,
+    /// `previous_window_id` doesn't match the prior window's subject name.
+    WindowIdMismatch,
+    /// `chain_length` didn't increment by exactly one from the prior window.
+    LengthGap,
+    /// `genesis_window_id` changed partway through the chain.
+    GenesisMismatch,
+}
+
+/// One broken sequencing invariant discovered by [`verify_chain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainBreak {
+    /// Index, within the slice passed to [`verify_chain`], of the window
+    /// where the break was detected.
+    pub window_index: usize,
+    /// What kind of break this is.
+    pub kind: ChainBreakKind,
+    /// Human-readable detail.
+    pub message: String,
+}
+
+/// Report produced by [`verify_chain`]: empty `breaks` means the whole
+/// sequence verified as an unbroken chain.
+#[derive(Debug, Clone, Default)]
+pub struct ChainReport {
+    pub breaks: Vec<ChainBreak>,
+}
+
+impl ChainReport {
+    /// Whether the chain verified with no breaks.
+    pub fn is_valid(&self) -> bool {
+        self.breaks.is_empty()
+    }
+}
+
+/// Walk `windows` in order and verify the tamper-evident sequencing
+/// invariants of their `ChainDescriptor`s end to end. An empty slice
+/// trivially verifies.
+pub fn verify_chain(windows: &[StreamWindowAttestation]) -> ChainReport {
+    let mut report = ChainReport::default();
+    if windows.is_empty() {
+        return report;
+    }
+
+    let first_chain = windows[0].predicate.integrity.chain.as_ref();
+    let genesis_id = match first_chain {
+        Some(chain) if chain.genesis_window_id.is_some() && chain.chain_length == Some(1) => {
+            chain.genesis_window_id.clone()
+        }
+        Some(_) => {
+            report.breaks.push(ChainBreak {
+                window_index: 0,
+                kind: ChainBreakKind::MissingGenesis,
+                message: "first window's ChainDescriptor is not a genesis link (expected \
+                          genesis_window_id set and chain_length == 1)"
+                    .to_string(),
+            });
+            None
+        }
+        None => {
+            report.breaks.push(ChainBreak {
+                window_index: 0,
+                kind: ChainBreakKind::MissingChain,
+                message: "first window has no ChainDescriptor".to_string(),
+            });
+            None
+        }
+    };
+
+    for i in 1..windows.len() {
+        let prior = &windows[i - 1];
+        let current = &windows[i];
+
+        let chain = match &current.predicate.integrity.chain {
+            Some(chain) => chain,
+            None => {
+                report.breaks.push(ChainBreak {
+                    window_index: i,
+                    kind: ChainBreakKind::MissingChain,
+                    message: format!("window {i} has no ChainDescriptor"),
+                });
+                continue;
+            }
+        };
+
+        let prior_root = &prior.predicate.integrity.merkle_tree.root;
+        match &chain.previous_merkle_root {
+            Some(root) if root == prior_root => {}
+            other => report.breaks.push(ChainBreak {
+                window_index: i,
+                kind: ChainBreakKind::RootMismatch,
+                message: format!(
+                    "window {i} previous_merkle_root {other:?} does not match prior window's root {prior_root}"
+                ),
+            }),
+        }
+
+        let prior_name = prior.subject.first().map(|s| s.name.as_str());
+        match (&chain.previous_window_id, prior_name) {
+            (Some(prev_id), Some(name)) if prev_id == name => {}
+            _ => report.breaks.push(ChainBreak {
+                window_index: i,
+                kind: ChainBreakKind::WindowIdMismatch,
+                message: format!(
+                    "window {i} previous_window_id {:?} does not match prior window's subject name {:?}",
+                    chain.previous_window_id, prior_name
+                ),
+            }),
+        }
+
+        let prior_length = prior
+            .predicate
+            .integrity
+            .chain
+            .as_ref()
+            .and_then(|c| c.chain_length);
+        match (prior_length, chain.chain_length) {
+            (Some(prev_len), Some(len)) if len == prev_len + 1 => {}
+            _ => report.breaks.push(ChainBreak {
+                window_index: i,
+                kind: ChainBreakKind::LengthGap,
+                message: format!(
+                    "window {i} chain_length {:?} does not follow prior window's {:?} by exactly one",
+                    chain.chain_length, prior_length
+                ),
+            }),
+        }
+
+        if chain.genesis_window_id != genesis_id {
+            report.breaks.push(ChainBreak {
+                window_index: i,
+                kind: ChainBreakKind::GenesisMismatch,
+                message: format!(
+                    "window {i} genesis_window_id {:?} does not match chain's genesis {:?}",
+                    chain.genesis_window_id, genesis_id
+                ),
+            });
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::stream_window::{
+        ChainDescriptor, IntegrityDescriptor, MerkleTreeDescriptor, StreamDescriptor,
+        WindowDescriptor,
+    };
+    use crate::types::common::HashAlgorithm;
+    use crate::types::{Digest, StreamWindowAttestation, Subject};
+
+    fn window(name: &str, root: &str, chain: Option<ChainDescriptor>) -> StreamWindowAttestation {
+        let merkle = MerkleTreeDescriptor::new(HashAlgorithm::Sha256, 10, root);
+        let mut integrity = IntegrityDescriptor::new(merkle);
+        if let Some(chain) = chain {
+            integrity = integrity.with_chain(chain);
+        }
+
+        StreamWindowAttestation::builder()
+            .subject(Subject::new(name, Digest::new("b".repeat(64))))
+            .stream(StreamDescriptor::new("iot_sensors"))
+            .window(WindowDescriptor::tumbling("PT1M"))
+            .integrity(integrity)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_chain_passes_for_valid_sequence() {
+        let w1 = window("window_1", &"a".repeat(64), Some(ChainDescriptor::genesis("window_1")));
+        let w2 = window(
+            "window_2",
+            &"b".repeat(64),
+            Some(ChainDescriptor::linked("window_1", "a".repeat(64), 2)),
+        );
+        let mut w2 = w2;
+        w2.predicate.integrity.chain.as_mut().unwrap().genesis_window_id =
+            Some("window_1".to_string());
+
+        let report = verify_chain(&[w1, w2]);
+        assert!(report.is_valid(), "{:?}", report.breaks);
+    }
+
+    #[test]
+    fn test_verify_chain_empty_is_valid() {
+        assert!(verify_chain(&[]).is_valid());
+    }
+
+    #[test]
+    fn test_verify_chain_flags_first_window_without_chain() {
+        let w1 = window("window_1", &"a".repeat(64), None);
+        let report = verify_chain(&[w1]);
+        assert_eq!(report.breaks.len(), 1);
+        assert_eq!(report.breaks[0].kind, ChainBreakKind::MissingChain);
+    }
+
+    #[test]
+    fn test_verify_chain_flags_root_mismatch() {
+        let w1 = window("window_1", &"a".repeat(64), Some(ChainDescriptor::genesis("window_1")));
+        let mut bad_chain = ChainDescriptor::linked("window_1", "c".repeat(64), 2);
+        bad_chain.genesis_window_id = Some("window_1".to_string());
+        let w2 = window("window_2", &"b".repeat(64), Some(bad_chain));
+
+        let report = verify_chain(&[w1, w2]);
+        assert!(report.breaks.iter().any(|b| b.kind == ChainBreakKind::RootMismatch));
+    }
+
+    #[test]
+    fn test_verify_chain_flags_length_gap() {
+        let w1 = window("window_1", &"a".repeat(64), Some(ChainDescriptor::genesis("window_1")));
+        let mut bad_chain = ChainDescriptor::linked("window_1", "a".repeat(64), 3);
+        bad_chain.genesis_window_id = Some("window_1".to_string());
+        let w2 = window("window_2", &"b".repeat(64), Some(bad_chain));
+
+        let report = verify_chain(&[w1, w2]);
+        assert!(report.breaks.iter().any(|b| b.kind == ChainBreakKind::LengthGap));
+    }
+
+    #[test]
+    fn test_verify_chain_flags_reordered_window() {
+        let w1 = window("window_1", &"a".repeat(64), Some(ChainDescriptor::genesis("window_1")));
+        let mut chain2 = ChainDescriptor::linked("window_1", "a".repeat(64), 2);
+        chain2.genesis_window_id = Some("window_1".to_string());
+        let w2 = window("window_2", &"b".repeat(64), Some(chain2));
+        let mut chain3 = ChainDescriptor::linked("window_1", "b".repeat(64), 3);
+        chain3.genesis_window_id = Some("window_1".to_string());
+        let w3 = window("window_3", &"c".repeat(64), Some(chain3));
+
+        // Swap windows 2 and 3 out of order.
+        let report = verify_chain(&[w1, w3, w2]);
+        assert!(!report.is_valid());
+    }
+}
@@ -0,0 +1,110 @@
+//! Populate `Executor` from the container runtime that actually ran the
+//! transform, instead of hand-written string literals.
+//!
+//! Gated behind the `docker` feature since `bollard` (and the `tokio`
+//! runtime it requires) is an optional dependency most users of the SDK
+//! don't need.
+
+use std::collections::HashMap;
+
+use bollard::container::InspectContainerOptions;
+use bollard::Docker;
+
+use crate::error::{MakotoError, Result};
+use crate::types::common::IsolationLevel;
+use crate::types::transform::{CodeReference, Executor};
+
+/// Everything discovered about the container that ran a transform: the
+/// `Executor` itself, and a `CodeReference` pointing at the exact image
+/// digest, suitable for attaching to `TransformDefinition::with_code_ref`.
+#[derive(Debug, Clone)]
+pub struct ContainerIntrospection {
+    /// Executor populated from the container's runtime configuration.
+    pub executor: Executor,
+    /// Reference to the image that produced the container, keyed by digest.
+    pub image_code_ref: CodeReference,
+}
+
+impl Executor {
+    /// Query the Docker Engine API for `container_id` and build an
+    /// `Executor` (platform, version, environment, isolation) plus a
+    /// `CodeReference` to the image that ran it.
+    pub async fn from_container(docker: &Docker, container_id: &str) -> Result<ContainerIntrospection> {
+        let inspect = docker
+            .inspect_container(container_id, None::<InspectContainerOptions>)
+            .await
+            .map_err(|e| MakotoError::InvalidAttestation(format!("docker inspect failed: {}", e)))?;
+
+        let image_digest = inspect.image.clone().unwrap_or_default();
+        let created = inspect.created.clone();
+        let runtime = inspect
+            .host_config
+            .as_ref()
+            .and_then(|h| h.runtime.clone())
+            .unwrap_or_default();
+        let privileged = inspect
+            .host_config
+            .as_ref()
+            .and_then(|h| h.privileged)
+            .unwrap_or(false);
+
+        let mut version = HashMap::new();
+        version.insert("image_digest".to_string(), image_digest.clone());
+        if !runtime.is_empty() {
+            version.insert("runtime".to_string(), runtime.clone());
+        }
+        if let Some(created) = created {
+            version.insert("created".to_string(), created);
+        }
+
+        let executor = Executor::new(format!("container:{}", container_id))
+            .with_platform("docker")
+            .with_environment("container")
+            .with_isolation(isolation_for_runtime(&runtime, privileged))
+            .with_version(version);
+
+        let image_code_ref = CodeReference::new(format!("docker://{}", image_digest));
+
+        Ok(ContainerIntrospection {
+            executor,
+            image_code_ref,
+        })
+    }
+}
+
+/// Infer the isolation level from the container's configured runtime.
+///
+/// Sandboxed runtimes (gVisor's `runsc`, Kata Containers) get VM-grade
+/// isolation; a privileged container gets none; everything else is treated
+/// as ordinary container (namespace/cgroup) isolation.
+fn isolation_for_runtime(runtime: &str, privileged: bool) -> IsolationLevel {
+    let runtime = runtime.to_lowercase();
+
+    if runtime.contains("kata") || runtime.contains("runsc") || runtime.contains("gvisor") {
+        IsolationLevel::Vm
+    } else if privileged {
+        IsolationLevel::None
+    } else {
+        IsolationLevel::Container
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isolation_for_kata_runtime() {
+        assert_eq!(isolation_for_runtime("kata-runtime", false), IsolationLevel::Vm);
+    }
+
+    #[test]
+    fn test_isolation_for_privileged_container() {
+        assert_eq!(isolation_for_runtime("runc", true), IsolationLevel::None);
+    }
+
+    #[test]
+    fn test_isolation_for_plain_container() {
+        assert_eq!(isolation_for_runtime("runc", false), IsolationLevel::Container);
+    }
+}
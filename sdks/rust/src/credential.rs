@@ -0,0 +1,232 @@
+//! Transport a [`Dbom`] as a W3C Verifiable Credential, signed as a compact
+//! VC-JWT, for exchange in DID-based SSI ecosystems.
+//!
+//! The DBOM becomes the credential's `credentialSubject`; claims are mapped
+//! onto the registered JWT names (`iss`, `nbf`, `exp`, `jti`) per the VC-JWT
+//! encoding, and signed with ES256 using the same P-256 keys as
+//! [`crate::signing::MakotoSigner`].
+
+use chrono::Utc;
+use p256::ecdsa::Signature;
+use serde_json::{json, Value};
+
+use crate::error::{MakotoError, Result};
+use crate::signing::{MakotoSigner, MakotoVerifier};
+use crate::types::Dbom;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+const VC_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+
+/// Resolves an issuer DID to the public key that should verify its VC-JWTs.
+pub trait DidResolver {
+    /// Look up the verifying key for `issuer_did`.
+    fn resolve(&self, issuer_did: &str) -> Result<MakotoVerifier>;
+}
+
+impl Dbom {
+    /// Wrap this DBOM as a W3C Verifiable Credential and sign it as a
+    /// compact VC-JWT.
+    ///
+    /// `issuanceDate`/`nbf` come from `metadata.created` (defaulting to
+    /// now if unset), `expirationDate`/`exp` from `metadata.valid_until`
+    /// (omitted entirely if unset), and `jti` is this DBOM's own
+    /// `dbom_id`. The full VC document, with this DBOM as
+    /// `credentialSubject`, is carried under the `vc` claim.
+    pub fn into_vc_jwt(&self, signing_key: &MakotoSigner, issuer_did: &str) -> Result<String> {
+        let issuance_date = self
+            .metadata
+            .as_ref()
+            .and_then(|m| m.created)
+            .unwrap_or_else(Utc::now);
+        let expiration_date = self.metadata.as_ref().and_then(|m| m.valid_until);
+
+        let mut vc = json!({
+            "@context": [VC_CONTEXT],
+            "type": ["VerifiableCredential", "DataBillOfMaterials"],
+            "issuer": issuer_did,
+            "issuanceDate": issuance_date.to_rfc3339(),
+            "credentialSubject": self,
+        });
+        if let Some(exp) = expiration_date {
+            vc["expirationDate"] = json!(exp.to_rfc3339());
+        }
+
+        let mut claims = serde_json::Map::new();
+        claims.insert("vc".to_string(), vc);
+        claims.insert("iss".to_string(), json!(issuer_did));
+        claims.insert("nbf".to_string(), json!(issuance_date.timestamp()));
+        claims.insert("jti".to_string(), json!(self.dbom_id));
+        if let Some(exp) = expiration_date {
+            claims.insert("exp".to_string(), json!(exp.timestamp()));
+        }
+
+        encode_jws(&Value::Object(claims), signing_key)
+    }
+}
+
+/// Verify a VC-JWT produced by [`Dbom::into_vc_jwt`]: resolve the `iss` DID
+/// to a key via `resolver`, check the ES256 signature, reject an expired
+/// `exp`, and finally run the decoded DBOM through [`Dbom::validate`].
+pub fn verify_vc_jwt(jwt: &str, resolver: &dyn DidResolver) -> Result<Dbom> {
+    let (header_b64, payload_b64, signature_b64) = split_jws(jwt)?;
+
+    let payload_bytes = URL_SAFE_NO_PAD
+        .decode(payload_b64)
+        .map_err(|e| MakotoError::Signature(format!("invalid VC-JWT payload base64: {e}")))?;
+    let claims: Value = serde_json::from_slice(&payload_bytes)?;
+
+    let issuer = claims
+        .get("iss")
+        .and_then(Value::as_str)
+        .ok_or_else(|| MakotoError::MissingField("iss".to_string()))?;
+    let verifier = resolver.resolve(issuer)?;
+
+    let signing_input = format!("{header_b64}.{payload_b64}");
+    let signature_bytes = URL_SAFE_NO_PAD
+        .decode(signature_b64)
+        .map_err(|e| MakotoError::Signature(format!("invalid VC-JWT signature base64: {e}")))?;
+    let signature = Signature::from_slice(&signature_bytes)
+        .map_err(|e| MakotoError::Signature(format!("invalid VC-JWT signature format: {e}")))?;
+
+    if !verifier.verify(signing_input.as_bytes(), &signature)? {
+        return Err(MakotoError::Signature(
+            "VC-JWT signature verification failed".to_string(),
+        ));
+    }
+
+    if let Some(exp) = claims.get("exp").and_then(Value::as_i64) {
+        if exp < Utc::now().timestamp() {
+            return Err(MakotoError::Signature("VC-JWT has expired".to_string()));
+        }
+    }
+
+    let credential_subject = claims
+        .get("vc")
+        .and_then(|vc| vc.get("credentialSubject"))
+        .ok_or_else(|| MakotoError::MissingField("vc.credentialSubject".to_string()))?;
+
+    let dbom: Dbom = serde_json::from_value(credential_subject.clone())?;
+    dbom.validate()?;
+    Ok(dbom)
+}
+
+/// Encode `claims` as a compact ES256 JWS: `base64url(header).base64url(claims).base64url(signature)`.
+fn encode_jws(claims: &Value, signer: &MakotoSigner) -> Result<String> {
+    let header = json!({"alg": "ES256", "typ": "JWT"});
+    let header_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?);
+    let claims_b64 = URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims)?);
+
+    let signing_input = format!("{header_b64}.{claims_b64}");
+    let signature = signer.sign(signing_input.as_bytes())?;
+    let signature_b64 = URL_SAFE_NO_PAD.encode(signature.to_bytes());
+
+    Ok(format!("{signing_input}.{signature_b64}"))
+}
+
+/// Split a compact JWS into its three base64url segments.
+fn split_jws(jwt: &str) -> Result<(&str, &str, &str)> {
+    let mut parts = jwt.split('.');
+    let header = parts
+        .next()
+        .ok_or_else(|| MakotoError::Signature("malformed VC-JWT: missing header".to_string()))?;
+    let payload = parts
+        .next()
+        .ok_or_else(|| MakotoError::Signature("malformed VC-JWT: missing payload".to_string()))?;
+    let signature = parts
+        .next()
+        .ok_or_else(|| MakotoError::Signature("malformed VC-JWT: missing signature".to_string()))?;
+    if parts.next().is_some() {
+        return Err(MakotoError::Signature(
+            "malformed VC-JWT: too many segments".to_string(),
+        ));
+    }
+    Ok((header, payload, signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::common::MakotoLevel;
+    use crate::types::dbom::{DatasetInfo, DbomDigest, DbomMetadata, Source};
+    use chrono::Duration;
+
+    struct StaticResolver(MakotoVerifier);
+
+    impl DidResolver for StaticResolver {
+        fn resolve(&self, _issuer_did: &str) -> Result<MakotoVerifier> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn sample_dbom(metadata: Option<DbomMetadata>) -> Dbom {
+        let dataset = DatasetInfo::new(
+            "fraud-detection-training",
+            "1.0.0",
+            Utc::now(),
+            DbomDigest::new("a".repeat(64)),
+            MakotoLevel::L2,
+        );
+        let source = Source::new(
+            "customer_transactions",
+            "https://makoto.dev/origin/v1",
+            MakotoLevel::L2,
+        );
+
+        let mut builder = Dbom::builder()
+            .id("urn:dbom:example.com:fraud-detection-v1")
+            .dataset(dataset)
+            .source(source);
+        if let Some(metadata) = metadata {
+            builder = builder.metadata(metadata);
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_into_vc_jwt_round_trips() {
+        let signer = MakotoSigner::generate();
+        let dbom = sample_dbom(None);
+
+        let jwt = dbom.into_vc_jwt(&signer, "did:example:issuer").unwrap();
+        assert_eq!(jwt.split('.').count(), 3);
+
+        let resolver = StaticResolver(signer.verifying_key());
+        let decoded = verify_vc_jwt(&jwt, &resolver).unwrap();
+        assert_eq!(decoded, dbom);
+    }
+
+    #[test]
+    fn test_verify_vc_jwt_rejects_wrong_key() {
+        let signer = MakotoSigner::generate();
+        let dbom = sample_dbom(None);
+        let jwt = dbom.into_vc_jwt(&signer, "did:example:issuer").unwrap();
+
+        let wrong_signer = MakotoSigner::generate();
+        let resolver = StaticResolver(wrong_signer.verifying_key());
+        assert!(verify_vc_jwt(&jwt, &resolver).is_err());
+    }
+
+    #[test]
+    fn test_verify_vc_jwt_rejects_expired_credential() {
+        let signer = MakotoSigner::generate();
+        let metadata = DbomMetadata {
+            generator: None,
+            created: Some(Utc::now() - Duration::days(2)),
+            valid_until: Some(Utc::now() - Duration::days(1)),
+            access_control: None,
+            tags: None,
+        };
+        let dbom = sample_dbom(Some(metadata));
+        let jwt = dbom.into_vc_jwt(&signer, "did:example:issuer").unwrap();
+
+        let resolver = StaticResolver(signer.verifying_key());
+        assert!(verify_vc_jwt(&jwt, &resolver).is_err());
+    }
+
+    #[test]
+    fn test_verify_vc_jwt_rejects_malformed_jwt() {
+        let signer = MakotoSigner::generate();
+        let resolver = StaticResolver(signer.verifying_key());
+        assert!(verify_vc_jwt("not-a-jwt", &resolver).is_err());
+    }
+}
@@ -44,6 +44,27 @@ pub enum MakotoError {
     /// IO error.
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
+
+    /// DBOM lineage DAG structural validation failed; enumerates every
+    /// broken edge rather than stopping at the first.
+    #[error("DBOM lineage DAG validation failed with {} issue(s): {}", .0.len(), .0.join("; "))]
+    DagValidationError(Vec<String>),
+
+    /// Stream window position/offset contiguity check failed; enumerates
+    /// every gap or overlap rather than stopping at the first.
+    #[error("stream window position contiguity check failed with {} issue(s): {}", .0.len(), .0.join("; "))]
+    PositionGapError(Vec<String>),
+
+    /// A signature or key declares a [`crate::signing::SignatureAlgorithm`]
+    /// this SDK has no backend for (recognized on the wire, but not
+    /// implemented).
+    #[error("unsupported signature algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+
+    /// A key's validity window had already lapsed (or not yet begun) at the
+    /// reference time a signature was checked against.
+    #[error("key '{key_id}' is not valid at the reference time: {reason}")]
+    KeyExpired { key_id: String, reason: String },
 }
 
 /// Result type alias for Makoto operations.
@@ -0,0 +1,454 @@
+//! Keyless identity verification — Sigstore Fulcio-style: an ephemeral
+//! signing key is vouched for by a short-lived certificate binding it to an
+//! OIDC identity (a human's email or a workload's SPIFFE URI), rather than
+//! by a long-lived [`crate::signing::MakotoVerifier`] key the signer must
+//! manage and protect.
+//!
+//! [`Certificate`] is deliberately not a general X.509 implementation —
+//! only the fields [`verify_keyless`] needs (a public key, the SAN it's
+//! bound to, the issuer that vouched for it, and that issuer's signature)
+//! — chained the same way [`crate::credential`]'s VC-JWTs and
+//! [`crate::signing::LogCheckpoint`]s are: a canonical body signed with the
+//! same ECDSA P-256 keys as everything else in this SDK.
+
+use crate::error::{MakotoError, Result};
+use crate::signing::{MakotoSigner, MakotoVerifier, SignedAttestation};
+use crate::verification::VerificationResult;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, Utc};
+use p256::ecdsa::Signature;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A short-lived certificate binding `public_key` to an OIDC identity
+/// (`san`), vouched for by `issuer`'s signature, valid only for
+/// `[not_before, not_after]` — keyless signing keys are ephemeral, so
+/// unlike [`crate::signing::MakotoVerifier`]'s long-lived keys, whether a
+/// certificate was valid has to be checked against a specific point in
+/// time (see [`Self::is_valid_at`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Certificate {
+    /// SEC1-encoded public key this certificate vouches for.
+    pub public_key: Vec<u8>,
+
+    /// Subject Alternative Name bound to this key, e.g.
+    /// `"mailto:ci@example.com"` or `"spiffe://example.com/ci"`.
+    pub san: String,
+
+    /// Name of the issuer that signed this certificate, matched against a
+    /// [`TrustRoots`] entry.
+    pub issuer: String,
+
+    /// Start of this certificate's validity window.
+    pub not_before: DateTime<Utc>,
+
+    /// End of this certificate's validity window.
+    pub not_after: DateTime<Utc>,
+
+    /// Base64-encoded ECDSA P-256 signature over this certificate's
+    /// canonical body, from the issuer's key.
+    pub signature: String,
+}
+
+impl Certificate {
+    /// Issue a certificate binding `public_key` to `san`, signed by
+    /// `issuer_signer` under the name `issuer`, valid for
+    /// `[not_before, not_after]`.
+    pub fn issue(
+        public_key: Vec<u8>,
+        san: impl Into<String>,
+        issuer: impl Into<String>,
+        not_before: DateTime<Utc>,
+        not_after: DateTime<Utc>,
+        issuer_signer: &MakotoSigner,
+    ) -> Result<Self> {
+        let san = san.into();
+        let issuer = issuer.into();
+        let signature = issuer_signer.sign(&Self::canonical_body(
+            &public_key,
+            &san,
+            &issuer,
+            not_before,
+            not_after,
+        ))?;
+
+        Ok(Self {
+            public_key,
+            san,
+            issuer,
+            not_before,
+            not_after,
+            signature: BASE64.encode(signature.to_bytes()),
+        })
+    }
+
+    /// Verify this certificate's signature was produced by `verifier`'s key.
+    pub fn verify_signature(&self, verifier: &MakotoVerifier) -> Result<bool> {
+        let body = Self::canonical_body(
+            &self.public_key,
+            &self.san,
+            &self.issuer,
+            self.not_before,
+            self.not_after,
+        );
+
+        let sig_bytes = BASE64
+            .decode(&self.signature)
+            .map_err(|e| MakotoError::Signature(format!("Invalid certificate signature base64: {}", e)))?;
+        let signature = Signature::from_slice(&sig_bytes)
+            .map_err(|e| MakotoError::Signature(format!("Invalid certificate signature format: {}", e)))?;
+
+        verifier.verify(&body, &signature)
+    }
+
+    /// Whether `at` falls within this certificate's `[not_before, not_after]`
+    /// validity window — checked against the signing-time timestamp a
+    /// transparency log vouches for, not the verifier's current clock, since
+    /// an ephemeral certificate is expected to have expired long before
+    /// anyone checks the signature it vouched for.
+    pub fn is_valid_at(&self, at: DateTime<Utc>) -> bool {
+        self.not_before <= at && at <= self.not_after
+    }
+
+    fn canonical_body(
+        public_key: &[u8],
+        san: &str,
+        issuer: &str,
+        not_before: DateTime<Utc>,
+        not_after: DateTime<Utc>,
+    ) -> Vec<u8> {
+        let mut body = Vec::with_capacity(public_key.len() + san.len() + issuer.len() + 48);
+        body.extend_from_slice(public_key);
+        body.push(0);
+        body.extend_from_slice(san.as_bytes());
+        body.push(0);
+        body.extend_from_slice(issuer.as_bytes());
+        body.push(0);
+        body.extend_from_slice(not_before.to_rfc3339().as_bytes());
+        body.push(0);
+        body.extend_from_slice(not_after.to_rfc3339().as_bytes());
+        body
+    }
+}
+
+/// A certificate authority that can issue short-lived identity-bound
+/// certificates from an OIDC identity token — the keyless signing flow's
+/// "Fulcio" role. Injectable so private deployments can point at their own
+/// CA; this SDK has no HTTP client dependency, so only the extension point
+/// is defined here, not a concrete implementation that calls a real CA's
+/// API over the network.
+pub trait CertificateAuthority {
+    /// Exchange an OIDC identity token and an ephemeral public key for a
+    /// short-lived certificate binding the two.
+    fn issue_certificate(&self, oidc_token: &str, public_key: &[u8]) -> Result<Certificate>;
+}
+
+/// Trusted root keys a certificate chain must terminate at, keyed by issuer
+/// name — analogous to a Fulcio trust bundle.
+#[derive(Debug, Clone, Default)]
+pub struct TrustRoots {
+    roots: HashMap<String, MakotoVerifier>,
+}
+
+impl TrustRoots {
+    /// An empty trust store; add roots with [`Self::with_root`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trust `verifier`'s key as the root for certificates issued under
+    /// `issuer`.
+    pub fn with_root(mut self, issuer: impl Into<String>, verifier: MakotoVerifier) -> Self {
+        self.roots.insert(issuer.into(), verifier);
+        self
+    }
+
+    /// Look up the trusted root key for `issuer`, if any.
+    pub fn get(&self, issuer: &str) -> Option<&MakotoVerifier> {
+        self.roots.get(issuer)
+    }
+}
+
+/// The identity a [`verify_keyless`] caller requires the certificate chain
+/// to resolve to: a SAN pattern (an exact match, or `*` as a wildcard
+/// anywhere in the pattern) and the trusted issuer name.
+#[derive(Debug, Clone)]
+pub struct IdentityPolicy {
+    /// SAN the certificate's `san` must match; `*` matches any substring.
+    pub san_pattern: String,
+    /// Issuer name the leaf certificate's chain must terminate at.
+    pub issuer: String,
+}
+
+impl IdentityPolicy {
+    /// Require `san_pattern` (supporting a `*` wildcard) and `issuer`.
+    pub fn new(san_pattern: impl Into<String>, issuer: impl Into<String>) -> Self {
+        Self {
+            san_pattern: san_pattern.into(),
+            issuer: issuer.into(),
+        }
+    }
+
+    fn matches_san(&self, san: &str) -> bool {
+        glob_match(&self.san_pattern, san)
+    }
+}
+
+/// Minimal single-`*`-wildcard glob match (no escaping, no `?`): `*` matches
+/// any substring (including empty), everything else must match literally.
+fn glob_match(pattern: &str, candidate: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == candidate,
+        Some((prefix, suffix)) => {
+            candidate.len() >= prefix.len() + suffix.len()
+                && candidate.starts_with(prefix)
+                && candidate.ends_with(suffix)
+        }
+    }
+}
+
+/// Verify `signed` was produced by the key certified by the leaf (first)
+/// certificate of `cert_chain`, that the chain terminates at a key in `roots`, and that
+/// the certified identity matches `identity_policy`.
+///
+/// `cert_chain` is ordered leaf-first: `cert_chain[0]` certifies the key
+/// that actually signed `signed`, `cert_chain[1]` certifies
+/// `cert_chain[0]`'s issuer, and so on, until the last certificate's issuer
+/// is found in `roots`. Returns [`crate::types::MakotoLevel::L2`] on
+/// success — the same trust level as a pre-shared key signature, since
+/// what's being trusted has shifted from "this specific key" to "this
+/// identity", not increased or decreased — with a message recording the
+/// resolved identity.
+pub fn verify_keyless(
+    signed: &SignedAttestation,
+    cert_chain: &[Certificate],
+    roots: &TrustRoots,
+    identity_policy: &IdentityPolicy,
+) -> VerificationResult {
+    let leaf = match cert_chain.first() {
+        Some(leaf) => leaf,
+        None => return VerificationResult::fail("certificate chain is empty"),
+    };
+
+    for (i, cert) in cert_chain.iter().enumerate() {
+        let issuer_verifier = match cert_chain.get(i + 1) {
+            Some(next) => match MakotoVerifier::from_bytes(&next.public_key) {
+                Ok(v) => v,
+                Err(e) => {
+                    return VerificationResult::fail(format!(
+                        "certificate {i}'s issuer key is invalid: {e}"
+                    ))
+                }
+            },
+            None => match roots.get(&cert.issuer) {
+                Some(root) => root.clone(),
+                None => {
+                    return VerificationResult::fail(format!(
+                        "certificate chain terminates at untrusted issuer '{}'",
+                        cert.issuer
+                    ))
+                }
+            },
+        };
+
+        match cert.verify_signature(&issuer_verifier) {
+            Ok(true) => {}
+            Ok(false) => {
+                return VerificationResult::fail(format!(
+                    "certificate {i} signature verification failed"
+                ))
+            }
+            Err(e) => return VerificationResult::fail(format!("certificate {i} error: {e}")),
+        }
+    }
+
+    let leaf_verifier = match MakotoVerifier::from_bytes(&leaf.public_key) {
+        Ok(v) => v,
+        Err(e) => return VerificationResult::fail(format!("leaf certificate key is invalid: {e}")),
+    };
+
+    match signed.verify(&leaf_verifier) {
+        Ok(true) => {}
+        Ok(false) => return VerificationResult::fail("envelope signature verification failed"),
+        Err(e) => return VerificationResult::fail(format!("signature error: {e}")),
+    }
+
+    let root_issuer = &cert_chain.last().expect("checked non-empty above").issuer;
+    if root_issuer != &identity_policy.issuer {
+        return VerificationResult::fail(format!(
+            "certificate chain terminates at issuer '{}', expected '{}'",
+            root_issuer, identity_policy.issuer
+        ));
+    }
+
+    if !identity_policy.matches_san(&leaf.san) {
+        return VerificationResult::fail(format!(
+            "certified identity '{}' does not match required SAN pattern '{}'",
+            leaf.san, identity_policy.san_pattern
+        ));
+    }
+
+    VerificationResult::pass(crate::types::MakotoLevel::L2)
+        .with_message(format!("Keyless signature verified for identity '{}'", leaf.san))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::MakotoSigner;
+    use crate::types::{Digest, OriginAttestation, Subject};
+    use crate::types::common::{CollectionMethod, SourceType};
+    use crate::types::origin::{Collector, Origin};
+    use chrono::Utc;
+
+    fn valid_window() -> (chrono::DateTime<Utc>, chrono::DateTime<Utc>) {
+        (Utc::now() - chrono::Duration::minutes(5), Utc::now() + chrono::Duration::minutes(5))
+    }
+
+    fn signed_attestation(signer: &MakotoSigner) -> SignedAttestation {
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let collector = Collector::new("https://example.com/collector/001");
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(collector)
+            .build()
+            .unwrap();
+
+        SignedAttestation::sign(&attestation, signer).unwrap()
+    }
+
+    #[test]
+    fn test_verify_keyless_passes_for_valid_chain() {
+        let root_signer = MakotoSigner::generate();
+        let leaf_signer = MakotoSigner::generate();
+
+        let leaf_cert = Certificate::issue(
+            leaf_signer.public_key_bytes(),
+            "mailto:ci@example.com",
+            "sigstore-root",
+            valid_window().0,
+            valid_window().1,
+            &root_signer,
+        )
+        .unwrap();
+
+        let signed = signed_attestation(&leaf_signer);
+        let roots = TrustRoots::new().with_root("sigstore-root", root_signer.verifying_key());
+        let policy = IdentityPolicy::new("*@example.com", "sigstore-root");
+
+        let result = verify_keyless(&signed, &[leaf_cert], &roots, &policy);
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(crate::types::MakotoLevel::L2));
+    }
+
+    #[test]
+    fn test_verify_keyless_rejects_untrusted_root() {
+        let root_signer = MakotoSigner::generate();
+        let other_root_signer = MakotoSigner::generate();
+        let leaf_signer = MakotoSigner::generate();
+
+        let leaf_cert = Certificate::issue(
+            leaf_signer.public_key_bytes(),
+            "mailto:ci@example.com",
+            "sigstore-root",
+            valid_window().0,
+            valid_window().1,
+            &root_signer,
+        )
+        .unwrap();
+
+        let signed = signed_attestation(&leaf_signer);
+        let roots =
+            TrustRoots::new().with_root("other-root", other_root_signer.verifying_key());
+        let policy = IdentityPolicy::new("*@example.com", "sigstore-root");
+
+        let result = verify_keyless(&signed, &[leaf_cert], &roots, &policy);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_keyless_rejects_san_mismatch() {
+        let root_signer = MakotoSigner::generate();
+        let leaf_signer = MakotoSigner::generate();
+
+        let leaf_cert = Certificate::issue(
+            leaf_signer.public_key_bytes(),
+            "mailto:ci@other.com",
+            "sigstore-root",
+            valid_window().0,
+            valid_window().1,
+            &root_signer,
+        )
+        .unwrap();
+
+        let signed = signed_attestation(&leaf_signer);
+        let roots = TrustRoots::new().with_root("sigstore-root", root_signer.verifying_key());
+        let policy = IdentityPolicy::new("*@example.com", "sigstore-root");
+
+        let result = verify_keyless(&signed, &[leaf_cert], &roots, &policy);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_keyless_rejects_tampered_envelope() {
+        let root_signer = MakotoSigner::generate();
+        let leaf_signer = MakotoSigner::generate();
+        let impostor_signer = MakotoSigner::generate();
+
+        let leaf_cert = Certificate::issue(
+            leaf_signer.public_key_bytes(),
+            "mailto:ci@example.com",
+            "sigstore-root",
+            valid_window().0,
+            valid_window().1,
+            &root_signer,
+        )
+        .unwrap();
+
+        // Signed by a different key than the one the certificate vouches for.
+        let signed = signed_attestation(&impostor_signer);
+        let roots = TrustRoots::new().with_root("sigstore-root", root_signer.verifying_key());
+        let policy = IdentityPolicy::new("*@example.com", "sigstore-root");
+
+        let result = verify_keyless(&signed, &[leaf_cert], &roots, &policy);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*@example.com", "ci@example.com"));
+        assert!(!glob_match("*@example.com", "ci@other.com"));
+        assert!(glob_match("spiffe://example.com/*", "spiffe://example.com/ci"));
+        assert!(glob_match("exact", "exact"));
+        assert!(!glob_match("exact", "not-exact"));
+    }
+
+    #[test]
+    fn test_certificate_is_valid_at_checks_window() {
+        let root_signer = MakotoSigner::generate();
+        let leaf_signer = MakotoSigner::generate();
+        let not_before = Utc::now() - chrono::Duration::minutes(5);
+        let not_after = Utc::now() + chrono::Duration::minutes(5);
+
+        let cert = Certificate::issue(
+            leaf_signer.public_key_bytes(),
+            "mailto:ci@example.com",
+            "sigstore-root",
+            not_before,
+            not_after,
+            &root_signer,
+        )
+        .unwrap();
+
+        assert!(cert.is_valid_at(Utc::now()));
+        assert!(!cert.is_valid_at(not_before - chrono::Duration::minutes(1)));
+        assert!(!cert.is_valid_at(not_after + chrono::Duration::minutes(1)));
+    }
+}
@@ -0,0 +1,215 @@
+//! Shamir splitting and quorum recovery for a [`MakotoSigner`]'s private
+//! key — key-custody plumbing, not a threshold-signing protocol.
+//!
+//! **This module does not implement threshold signing, and in particular
+//! is not FROST (RFC 9591).** FROST's entire point is that the group
+//! private key is never reconstructed: each of `t` participants publishes
+//! hiding/binding nonce commitments, a coordinator derives per-signer
+//! binding factors and a group commitment, each participant returns a
+//! partial signature, and the coordinator aggregates them — no single
+//! party, coordinator included, ever holds the whole key. [`reconstruct_signer`]
+//! does the opposite of that on purpose: it hands the caller back a
+//! complete, usable [`MakotoSigner`], which briefly means the full private
+//! key exists in one process's memory. A deployment whose security
+//! requirement is "no single party can ever reconstruct the key" is **not**
+//! satisfied by this module and needs a real threshold-signing protocol —
+//! an audited FROST crate (e.g. RustCrypto's `frost-p256`) — instead. This
+//! SDK also has no Schnorr verifier backend to check FROST's output against
+//! ([`crate::signing::MakotoVerifier::verify`] only handles ECDSA P-256;
+//! see [`crate::signing::SignatureAlgorithm`] for the same gap against
+//! Ed25519/RSA), and implementing FROST's binding-factor and challenge
+//! derivation by hand with no audited reference to check it against would
+//! be a bigger risk than not having the feature.
+//!
+//! What this module *does* provide is quorum-gated key recovery:
+//! [`split_signer`] splits a private key into `n` Shamir shares over the
+//! P-256 scalar field such that any `t` of them reconstruct it, via a
+//! random degree-`(t-1)` polynomial whose constant term is the secret,
+//! evaluated at distinct participant indices and recombined by Lagrange
+//! interpolation at `x = 0`. That's useful on its own — no single share
+//! holder can mint a signature, and a quorum must actively cooperate to
+//! bring the key back together — but the moment they do, the
+//! reconstructed key signs through the ordinary [`MakotoSigner`] path,
+//! producing a [`crate::signing::AttestationSignature`] indistinguishable
+//! from a non-quorum one, and the caller holding it should sign
+//! immediately and drop the result.
+
+use crate::error::{MakotoError, Result};
+use crate::signing::MakotoSigner;
+use p256::elliptic_curve::PrimeField;
+use p256::Scalar;
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// One participant's share of a split private key.
+#[derive(Debug, Clone)]
+pub struct KeyShare {
+    /// 1-based participant index (the Shamir x-coordinate). Must be unique
+    /// among shares presented to [`reconstruct_signer`].
+    pub participant_id: u16,
+    share: Scalar,
+}
+
+/// Split `signer`'s private key into `n` [`KeyShare`]s such that
+/// any `t` of them can reconstruct it, but fewer cannot.
+///
+/// Returns an error if `t` is zero or greater than `n`.
+pub fn split_signer(signer: &MakotoSigner, t: u16, n: u16) -> Result<Vec<KeyShare>> {
+    if t == 0 || t > n {
+        return Err(MakotoError::KeyError(format!(
+            "invalid threshold: need 1 <= t <= n, got t={}, n={}",
+            t, n
+        )));
+    }
+
+    let secret = scalar_from_bytes(&signer.ecdsa_scalar_bytes())?;
+
+    // Random polynomial f(x) = secret + c_1*x + ... + c_{t-1}*x^{t-1}; the
+    // constant term is the secret so f(0) recovers it.
+    let mut coefficients = Vec::with_capacity(t as usize);
+    coefficients.push(secret);
+    for _ in 1..t {
+        coefficients.push(random_nonzero_scalar());
+    }
+
+    Ok((1..=n)
+        .map(|id| KeyShare {
+            participant_id: id,
+            share: evaluate_polynomial(&coefficients, Scalar::from(u64::from(id))),
+        })
+        .collect())
+}
+
+/// Reconstruct the original private key from `shares` via Lagrange
+/// interpolation at `x = 0`, and wrap it back up as an ordinary
+/// [`MakotoSigner`].
+///
+/// Any `t` of the shares [`split_signer`] produced are sufficient; fewer
+/// than `t` reconstruct a different, useless key rather than failing
+/// loudly, since a Shamir share alone can't prove which polynomial it
+/// belongs to — callers are responsible for only invoking this once a
+/// quorum has actually agreed to cooperate. The reconstructed key lives in
+/// the returned [`MakotoSigner`] only as long as the caller holds onto it;
+/// sign with it immediately and let it drop.
+pub fn reconstruct_signer(shares: &[KeyShare]) -> Result<MakotoSigner> {
+    if shares.is_empty() {
+        return Err(MakotoError::KeyError(
+            "cannot reconstruct from zero shares".to_string(),
+        ));
+    }
+
+    let xs: Vec<Scalar> = shares
+        .iter()
+        .map(|s| Scalar::from(u64::from(s.participant_id)))
+        .collect();
+
+    let mut secret = Scalar::ZERO;
+    for (i, share) in shares.iter().enumerate() {
+        let mut lagrange = Scalar::ONE;
+        for (j, &xj) in xs.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            let denom = xj - xs[i];
+            let denom_inv: Option<Scalar> = denom.invert().into();
+            let denom_inv = denom_inv.ok_or_else(|| {
+                MakotoError::KeyError(
+                    "duplicate participant_id among shares passed to reconstruct_signer"
+                        .to_string(),
+                )
+            })?;
+            lagrange *= xj * denom_inv;
+        }
+        secret += share.share * lagrange;
+    }
+
+    let repr = secret.to_repr();
+    let bytes = <[u8; 32]>::try_from(repr.as_slice())
+        .expect("P-256 scalar representation is always 32 bytes");
+    MakotoSigner::from_ecdsa_scalar_bytes(&bytes)
+}
+
+fn scalar_from_bytes(bytes: &[u8]) -> Result<Scalar> {
+    let array = <[u8; 32]>::try_from(bytes)
+        .map_err(|_| MakotoError::KeyError("private key is not 32 bytes".to_string()))?;
+    let repr: Option<Scalar> = Scalar::from_repr(array.into()).into();
+    repr.ok_or_else(|| MakotoError::KeyError("private key is not a valid P-256 scalar".to_string()))
+}
+
+fn random_nonzero_scalar() -> Scalar {
+    loop {
+        let mut bytes = [0u8; 32];
+        OsRng.fill_bytes(&mut bytes);
+        let candidate: Option<Scalar> = Scalar::from_repr(bytes.into()).into();
+        if let Some(scalar) = candidate {
+            if scalar != Scalar::ZERO {
+                return scalar;
+            }
+        }
+    }
+}
+
+/// Evaluate `coefficients` (lowest-degree term first) at `x` via Horner's
+/// method.
+fn evaluate_polynomial(coefficients: &[Scalar], x: Scalar) -> Scalar {
+    let mut result = Scalar::ZERO;
+    for c in coefficients.iter().rev() {
+        result = result * x + c;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_and_reconstruct_recovers_original_key() {
+        let signer = MakotoSigner::generate();
+        let shares = split_signer(&signer, 3, 5).unwrap();
+
+        let reconstructed = reconstruct_signer(&shares[0..3]).unwrap();
+        assert_eq!(reconstructed.to_bytes(), signer.to_bytes());
+    }
+
+    #[test]
+    fn test_reconstruct_works_with_any_quorum_subset() {
+        let signer = MakotoSigner::generate();
+        let shares = split_signer(&signer, 3, 5).unwrap();
+
+        let subset = vec![shares[1].clone(), shares[3].clone(), shares[4].clone()];
+        let reconstructed = reconstruct_signer(&subset).unwrap();
+        assert_eq!(reconstructed.to_bytes(), signer.to_bytes());
+    }
+
+    #[test]
+    fn test_reconstructed_signer_produces_verifiable_signatures() {
+        let signer = MakotoSigner::generate();
+        let shares = split_signer(&signer, 2, 3).unwrap();
+
+        let reconstructed = reconstruct_signer(&shares[0..2]).unwrap();
+        let signature = reconstructed.sign(b"quorum-authorized payload").unwrap();
+
+        let verifier = signer.verifying_key();
+        assert!(verifier.verify(b"quorum-authorized payload", &signature).unwrap());
+    }
+
+    #[test]
+    fn test_split_signer_rejects_invalid_threshold() {
+        let signer = MakotoSigner::generate();
+        assert!(split_signer(&signer, 0, 5).is_err());
+        assert!(split_signer(&signer, 6, 5).is_err());
+    }
+
+    #[test]
+    fn test_below_quorum_share_count_does_not_reconstruct_original_key() {
+        let signer = MakotoSigner::generate();
+        let shares = split_signer(&signer, 3, 5).unwrap();
+
+        // Only 2 of the required 3 shares: recombination "succeeds" (Shamir
+        // shares can't prove which polynomial they came from) but yields a
+        // different, useless key rather than the original.
+        let reconstructed = reconstruct_signer(&shares[0..2]).unwrap();
+        assert_ne!(reconstructed.to_bytes(), signer.to_bytes());
+    }
+}
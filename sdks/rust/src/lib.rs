@@ -19,7 +19,7 @@
 //!
 //! - **L1**: Attestation exists (provenance available)
 //! - **L2**: Signed attestation (tamper-evident)
-//! - **L3**: Hardened signing (non-falsifiable, requires HSM)
+//! - **L3**: Transparency-log inclusion (non-falsifiable, independently checkable)
 //!
 //! ## Quick Start
 //!
@@ -122,23 +122,78 @@
 //! assert!(tree.verify_proof(&proof));
 //! ```
 
+#[cfg(feature = "arrow")]
+pub mod arrow_export;
+pub mod authz;
+pub mod batch;
+pub mod bundle;
+pub mod chain;
+#[cfg(feature = "docker")]
+pub mod container;
+pub mod credential;
 pub mod error;
 pub mod hash;
+pub mod keyless;
+pub mod keysplit;
+pub mod merkle;
+#[cfg(feature = "otel")]
+pub mod otel;
+pub mod privacy;
+pub mod prov;
+pub mod provenance;
+pub mod resolver;
 pub mod signing;
+pub mod sparse_merkle;
+pub mod transparency;
+pub mod trust;
 pub mod types;
 pub mod verification;
+pub mod verify;
 
 // Re-export commonly used items at crate root
+pub use authz::{
+    validate_authorization, Capability, CapabilityAction, Did, DidVerifier, UcanChain, UcanToken,
+};
+pub use batch::verify_batch;
+pub use bundle::{keyless_sign, verify_bundle, BundleInclusion, MakotoBundle, TransparencyLogClient, TrustConfig};
+pub use chain::{verify_chain, ChainBreak, ChainBreakKind, ChainReport};
+pub use credential::{verify_vc_jwt, DidResolver};
 pub use error::{MakotoError, Result};
-pub use hash::{sha256_hex, sha256_str, MerkleTree, MerkleProof};
-pub use signing::{MakotoSigner, MakotoVerifier, SignedAttestation};
+pub use hash::{
+    sha256_hex, sha256_str, verify_external_inclusion, verify_mmr_consistency, BatchMerkleProof,
+    ConsistencyProof, ExternalInclusionProof, HashMode, MerkleHasher, MerkleMountainRange,
+    MerkleProof, MerkleTree, MmrConsistencyProof, MmrConsistencyProofHex,
+};
+pub use keyless::{verify_keyless, Certificate, CertificateAuthority, IdentityPolicy, TrustRoots};
+pub use keysplit::{reconstruct_signer, split_signer, KeyShare};
+pub use merkle::{
+    build_window_tree, consistency_proof, inclusion_proof, verify_consistency, verify_inclusion,
+};
+pub use privacy::{compose_privacy_budget, ComposedPrivacyBudget, CompositionTheorem};
+pub use provenance::{AttestationResolver, ProvenanceGraph, ProvenanceVerification};
+pub use resolver::{
+    resolve_dbom_verification, DbomAttestationResolver, InMemoryAttestationResolver,
+    ResolvedAttestation,
+};
+pub use signing::{
+    AttestationClaims, InclusionProof, LogCheckpoint, MakotoKeyring, MakotoSigner, MakotoVerifier,
+    SignatureAlgorithm, SignedAttestation, TransparencyLogEntry, VerifiedKeyId, VerifierEntry,
+    VerifierSet,
+};
+pub use sparse_merkle::{SparseMerkleProof, SparseMerkleTree};
+pub use transparency::{InMemoryTransparencyLog, LogEntry, TransparencyLog};
+pub use trust::{TrustManifest, TrustRoot};
 pub use types::{
     Dbom, Digest, MakotoLevel, OriginAttestation, StreamWindowAttestation, Subject,
     TransformAttestation,
 };
 pub use verification::{
-    verify_attestation_json, verify_digest, verify_origin_structure,
-    verify_stream_window_structure, verify_transform_structure, AttestationType,
+    verify_attestation_json, verify_attestation_json_with_options, verify_attestation_json_with_trust,
+    verify_digest, verify_inclusion as verify_transparency_inclusion_bool, verify_origin_structure,
+    verify_record_in_window, verify_signed_attestation_multi, verify_signed_attestation_with_policy,
+    verify_stream_window_structure, verify_threshold, verify_transform_structure,
+    verify_transform_structure_with_authorization, verify_transparency_inclusion,
+    verify_window_chain, AttestationType, ValidationOptions, VerificationPolicy,
     VerificationResult,
 };
 
@@ -0,0 +1,226 @@
+//! Builds Merkle trees over a stream window's ordered records and
+//! generates/verifies the RFC 6962-style inclusion and consistency proofs
+//! that back [`MerkleTreeDescriptor`] and [`WindowVerification`].
+//!
+//! [`MerkleTreeDescriptor`] only records a root/leaf_count/tree_height
+//! summary; the actual tree lives here, reusing [`crate::hash::MerkleTree`]
+//! under [`HashMode::Rfc6962`] rather than re-implementing its hashing
+//! scheme. This lets a verifier prove a single record was included in a
+//! window ([`inclusion_proof`]/[`verify_inclusion`]) and that a later
+//! window's tree is an append-only extension of an earlier one
+//! ([`consistency_proof`]/[`verify_consistency`]), exchanging proofs out of
+//! band (e.g. via `WindowVerification.proof_endpoint`).
+//!
+//! [`WindowVerification`]: crate::types::stream_window::WindowVerification
+
+use crate::error::{MakotoError, Result};
+use crate::hash::{self, ConsistencyProof, HashMode, MerkleProof, MerkleTree};
+use crate::types::stream_window::MerkleTreeDescriptor;
+use crate::types::HashAlgorithm;
+
+impl MerkleTreeDescriptor {
+    /// Build a descriptor from a window's ordered records, populating
+    /// `algorithm`, `leaf_count`, `tree_height`, and the hex-encoded `root`.
+    ///
+    /// Uses RFC 6962 domain separation throughout: an empty window hashes
+    /// to `H("")`, and non-empty records hash the same way
+    /// [`build_window_tree`] does, so a proof generated from this
+    /// descriptor's records always verifies against its `root`.
+    pub fn from_leaves(records: &[&[u8]], algorithm: HashAlgorithm) -> Result<Self> {
+        if records.is_empty() {
+            let root = hash::empty_hash(algorithm)?;
+            return Ok(Self {
+                algorithm,
+                leaf_hash_algorithm: None,
+                leaf_count: 0,
+                tree_height: None,
+                root: hex::encode(root),
+                kind: None,
+            });
+        }
+
+        let tree = build_window_tree(records, algorithm)?;
+        Ok(Self {
+            algorithm,
+            leaf_hash_algorithm: None,
+            leaf_count: records.len() as u64,
+            tree_height: Some(tree.height() as u32),
+            root: tree.root_hex().expect("non-empty tree always has a root"),
+            kind: None,
+        })
+    }
+}
+
+/// Build the RFC 6962 Merkle tree over `records`, in order, using
+/// `algorithm` for both leaf and internal node hashing.
+///
+/// This is the tree [`MerkleTreeDescriptor::from_leaves`] summarizes, and
+/// the one [`inclusion_proof`] and [`consistency_proof`] operate against.
+pub fn build_window_tree(records: &[&[u8]], algorithm: HashAlgorithm) -> Result<MerkleTree> {
+    MerkleTree::from_leaves_with_options(records, algorithm, HashMode::Rfc6962)
+}
+
+/// Generate a proof that `records[leaf_index]` was included in the window's
+/// Merkle tree, to be checked against a [`MerkleTreeDescriptor`] via
+/// [`verify_inclusion`].
+pub fn inclusion_proof(
+    records: &[&[u8]],
+    algorithm: HashAlgorithm,
+    leaf_index: usize,
+) -> Result<MerkleProof> {
+    build_window_tree(records, algorithm)?.proof(leaf_index)
+}
+
+/// Verify an inclusion proof against a [`MerkleTreeDescriptor`]'s recorded
+/// root.
+///
+/// Returns [`MakotoError::MerkleError`] if the proof's algorithm doesn't
+/// match the descriptor's, rather than silently comparing roots produced
+/// under different hash functions.
+pub fn verify_inclusion(proof: &MerkleProof, descriptor: &MerkleTreeDescriptor) -> Result<bool> {
+    if proof.algorithm != descriptor.algorithm {
+        return Err(MakotoError::MerkleError(format!(
+            "proof algorithm {:?} does not match descriptor algorithm {:?}",
+            proof.algorithm, descriptor.algorithm
+        )));
+    }
+    let root = hash::hash_from_hex(&descriptor.root)?;
+    Ok(proof.verify(&root))
+}
+
+/// Prove that the tree built from `new_records` is an append-only extension
+/// of an earlier window's tree that had `old_leaf_count` records: the first
+/// `old_leaf_count` records of `new_records` are unchanged.
+pub fn consistency_proof(
+    new_records: &[&[u8]],
+    algorithm: HashAlgorithm,
+    old_leaf_count: u64,
+) -> Result<ConsistencyProof> {
+    build_window_tree(new_records, algorithm)?
+        .consistency_proof(old_leaf_count as usize, new_records.len())
+}
+
+/// Verify a consistency proof links `old_descriptor`'s root to
+/// `new_descriptor`'s root — i.e. that the newer window's tree only ever
+/// appended records after the older window's.
+pub fn verify_consistency(
+    proof: &ConsistencyProof,
+    old_descriptor: &MerkleTreeDescriptor,
+    new_descriptor: &MerkleTreeDescriptor,
+) -> Result<bool> {
+    if old_descriptor.algorithm != new_descriptor.algorithm {
+        return Err(MakotoError::MerkleError(format!(
+            "old window algorithm {:?} does not match new window algorithm {:?}",
+            old_descriptor.algorithm, new_descriptor.algorithm
+        )));
+    }
+    let old_root = hash::hash_from_hex(&old_descriptor.root)?;
+    let new_root = hash::hash_from_hex(&new_descriptor.root)?;
+    Ok(proof.verify(&old_root, &new_root))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_records(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| format!("record-{i}").into_bytes()).collect()
+    }
+
+    fn refs(records: &[Vec<u8>]) -> Vec<&[u8]> {
+        records.iter().map(|r| r.as_slice()).collect()
+    }
+
+    #[test]
+    fn test_from_leaves_populates_descriptor() {
+        let records = sample_records(5);
+        let descriptor = MerkleTreeDescriptor::from_leaves(&refs(&records), HashAlgorithm::Sha256).unwrap();
+
+        assert_eq!(descriptor.algorithm, HashAlgorithm::Sha256);
+        assert_eq!(descriptor.leaf_count, 5);
+        assert!(descriptor.tree_height.is_some());
+        assert_eq!(descriptor.root.len(), 64);
+    }
+
+    #[test]
+    fn test_from_leaves_empty_window_hashes_to_h_empty_string() {
+        let descriptor = MerkleTreeDescriptor::from_leaves(&[], HashAlgorithm::Sha256).unwrap();
+
+        assert_eq!(descriptor.leaf_count, 0);
+        assert!(descriptor.tree_height.is_none());
+        assert_eq!(descriptor.root, crate::hash::sha256_hex(b""));
+    }
+
+    #[test]
+    fn test_inclusion_proof_round_trips() {
+        let records = sample_records(7);
+        let leaves = refs(&records);
+        let descriptor = MerkleTreeDescriptor::from_leaves(&leaves, HashAlgorithm::Sha256).unwrap();
+
+        for i in 0..leaves.len() {
+            let proof = inclusion_proof(&leaves, HashAlgorithm::Sha256, i).unwrap();
+            assert!(verify_inclusion(&proof, &descriptor).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_inclusion_proof_rejects_tampered_record() {
+        let records = sample_records(4);
+        let leaves = refs(&records);
+        let descriptor = MerkleTreeDescriptor::from_leaves(&leaves, HashAlgorithm::Sha256).unwrap();
+
+        let mut proof = inclusion_proof(&leaves, HashAlgorithm::Sha256, 2).unwrap();
+        proof.leaf_hash[0] ^= 1;
+        assert!(!verify_inclusion(&proof, &descriptor).unwrap());
+    }
+
+    #[test]
+    fn test_inclusion_proof_rejects_algorithm_mismatch() {
+        let records = sample_records(3);
+        let leaves = refs(&records);
+        let descriptor = MerkleTreeDescriptor::from_leaves(&leaves, HashAlgorithm::Keccak256).unwrap();
+
+        let proof = inclusion_proof(&leaves, HashAlgorithm::Sha256, 0).unwrap();
+        assert!(verify_inclusion(&proof, &descriptor).is_err());
+    }
+
+    #[test]
+    fn test_inclusion_proof_rejects_out_of_range_index() {
+        let records = sample_records(2);
+        let leaves = refs(&records);
+        assert!(inclusion_proof(&leaves, HashAlgorithm::Sha256, 5).is_err());
+    }
+
+    #[test]
+    fn test_consistency_proof_confirms_append_only_extension() {
+        let old_records = sample_records(3);
+        let old_descriptor =
+            MerkleTreeDescriptor::from_leaves(&refs(&old_records), HashAlgorithm::Sha256).unwrap();
+
+        let new_records = sample_records(6);
+        let new_leaves = refs(&new_records);
+        let new_descriptor = MerkleTreeDescriptor::from_leaves(&new_leaves, HashAlgorithm::Sha256).unwrap();
+
+        let proof = consistency_proof(&new_leaves, HashAlgorithm::Sha256, old_descriptor.leaf_count).unwrap();
+        assert!(verify_consistency(&proof, &old_descriptor, &new_descriptor).unwrap());
+    }
+
+    #[test]
+    fn test_consistency_proof_rejects_rewritten_history() {
+        let old_records = sample_records(3);
+        let old_descriptor =
+            MerkleTreeDescriptor::from_leaves(&refs(&old_records), HashAlgorithm::Sha256).unwrap();
+
+        // Window 2 rewrites record-1 instead of only appending.
+        let mut tampered_records = sample_records(3);
+        tampered_records[1] = b"tampered".to_vec();
+        tampered_records.extend(sample_records(6).into_iter().skip(3));
+        let tampered_leaves = refs(&tampered_records);
+        let tampered_descriptor =
+            MerkleTreeDescriptor::from_leaves(&tampered_leaves, HashAlgorithm::Sha256).unwrap();
+
+        let proof =
+            consistency_proof(&tampered_leaves, HashAlgorithm::Sha256, old_descriptor.leaf_count).unwrap();
+        assert!(!verify_consistency(&proof, &old_descriptor, &tampered_descriptor).unwrap());
+    }
+}
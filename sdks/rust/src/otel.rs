@@ -0,0 +1,124 @@
+//! OpenTelemetry integration: capture `ExecutionMetadata` from finished
+//! spans, and emit attestations back out as spans/metrics.
+//!
+//! Lets a running pipeline produce a signed attestation and live telemetry
+//! from the same underlying data, instead of maintaining two separate code
+//! paths. Gated behind the `otel` feature since `opentelemetry` is an
+//! optional dependency most users of the SDK don't need.
+
+use chrono::{DateTime, Utc};
+use opentelemetry::metrics::Meter;
+use opentelemetry::trace::{Span, Tracer};
+use opentelemetry::{Key, KeyValue, Value};
+use opentelemetry_sdk::export::trace::SpanData;
+
+use crate::types::transform::ExecutionMetadata;
+use crate::types::TransformAttestation;
+
+const ATTR_RECORDS_INPUT: &str = "makoto.records_input";
+const ATTR_RECORDS_OUTPUT: &str = "makoto.records_output";
+const ATTR_RECORDS_DROPPED: &str = "makoto.records_dropped";
+const ATTR_RECORDS_MODIFIED: &str = "makoto.records_modified";
+const ATTR_BYTES_INPUT: &str = "makoto.bytes_input";
+const ATTR_BYTES_OUTPUT: &str = "makoto.bytes_output";
+
+impl ExecutionMetadata {
+    /// Derive `started_on`/`finished_on`/`duration_seconds` from a finished
+    /// span's timing, and pull record/byte counters from its `makoto.*`
+    /// attributes.
+    pub fn from_span(span: &SpanData) -> Self {
+        let started_on = DateTime::<Utc>::from(span.start_time);
+        let finished_on = DateTime::<Utc>::from(span.end_time);
+        let duration_seconds = span
+            .end_time
+            .duration_since(span.start_time)
+            .map(|d| d.as_secs_f64())
+            .ok();
+
+        let mut metadata = ExecutionMetadata {
+            invocation_id: Some(span.span_context.span_id().to_string()),
+            started_on: Some(started_on),
+            finished_on: Some(finished_on),
+            duration_seconds,
+            ..Default::default()
+        };
+
+        for kv in span.attributes.iter() {
+            let n = as_u64(kv.1);
+            match kv.0.as_str() {
+                ATTR_RECORDS_INPUT => metadata.records_input = n,
+                ATTR_RECORDS_OUTPUT => metadata.records_output = n,
+                ATTR_RECORDS_DROPPED => metadata.records_dropped = n,
+                ATTR_RECORDS_MODIFIED => metadata.records_modified = n,
+                ATTR_BYTES_INPUT => metadata.bytes_input = n,
+                ATTR_BYTES_OUTPUT => metadata.bytes_output = n,
+                _ => {}
+            }
+        }
+
+        metadata
+    }
+}
+
+fn as_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::I64(n) => u64::try_from(*n).ok(),
+        Value::F64(n) => Some(*n as u64),
+        _ => None,
+    }
+}
+
+impl TransformAttestation {
+    /// Record a span for this transform (named after
+    /// `TransformDefinition.name`, with executor/transform-type/isolation
+    /// attributes) and publish its record/byte counts as OTEL counters.
+    pub fn emit_otel<T: Tracer>(&self, tracer: &T, meter: &Meter) {
+        let mut span = tracer.start(self.predicate.transform.name.clone());
+
+        span.set_attribute(KeyValue::new(
+            Key::new("makoto.executor_id"),
+            self.predicate.executor.id.clone(),
+        ));
+        span.set_attribute(KeyValue::new(
+            Key::new("makoto.transform_type"),
+            self.predicate.transform.transform_type.clone(),
+        ));
+        if let Some(isolation) = self.predicate.executor.isolation {
+            span.set_attribute(KeyValue::new(
+                Key::new("makoto.isolation"),
+                format!("{:?}", isolation),
+            ));
+        }
+
+        if let Some(metadata) = &self.predicate.metadata {
+            emit_counter(meter, ATTR_RECORDS_INPUT, metadata.records_input);
+            emit_counter(meter, ATTR_RECORDS_OUTPUT, metadata.records_output);
+            emit_counter(meter, ATTR_RECORDS_DROPPED, metadata.records_dropped);
+            emit_counter(meter, ATTR_BYTES_INPUT, metadata.bytes_input);
+            emit_counter(meter, ATTR_BYTES_OUTPUT, metadata.bytes_output);
+        }
+
+        span.end();
+    }
+}
+
+fn emit_counter(meter: &Meter, name: &'static str, value: Option<u64>) {
+    if let Some(value) = value {
+        meter.u64_counter(name).init().add(value, &[]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_u64_from_i64() {
+        assert_eq!(as_u64(&Value::I64(42)), Some(42));
+    }
+
+    #[test]
+    fn test_as_u64_ignores_strings() {
+        assert_eq!(as_u64(&Value::String("nope".into())), None);
+    }
+}
@@ -0,0 +1,403 @@
+//! W3C PROV export for interoperability with provenance tooling.
+//!
+//! Converts a [`TransformAttestation`] into the PROV data model (entities,
+//! activities, agents, and the relations between them) so attestations can
+//! be loaded into existing provenance stores and queried alongside other
+//! lineage data. See <https://www.w3.org/TR/prov-o/>.
+
+use serde_json::{json, Value};
+
+use crate::types::{Dbom, TransformAttestation};
+
+const PROV_NS: &str = "http://www.w3.org/ns/prov#";
+const MAKOTO_NS: &str = "https://makoto.dev/ns/prov#";
+
+/// Build the PROV-JSON document for a transform attestation.
+///
+/// Entities are keyed by IRI (derived from the subject/input name), using
+/// `entity`, `activity`, and `agent` top-level maps as defined by the
+/// PROV-JSON spec.
+pub fn to_prov_json(attestation: &TransformAttestation) -> Value {
+    let activity_iri = activity_iri(attestation);
+    let executor = &attestation.predicate.executor;
+    let agent_iri = executor.id.clone();
+
+    let mut entities = serde_json::Map::new();
+    let mut used = serde_json::Map::new();
+    let mut was_generated_by = serde_json::Map::new();
+    let mut was_derived_from = serde_json::Map::new();
+
+    for input in &attestation.predicate.inputs {
+        let iri = entity_iri(&input.name, &input.digest.sha256);
+        entities.insert(
+            iri.clone(),
+            json!({ "makoto:digest": input.digest.sha256 }),
+        );
+        used.insert(
+            format!("_:used_{}", sanitize(&input.name)),
+            json!({ "prov:activity": activity_iri, "prov:entity": iri }),
+        );
+    }
+
+    for subject in &attestation.subject {
+        let iri = entity_iri(&subject.name, &subject.digest.sha256);
+        entities.insert(
+            iri.clone(),
+            json!({ "makoto:digest": subject.digest.sha256 }),
+        );
+        was_generated_by.insert(
+            format!("_:gen_{}", sanitize(&subject.name)),
+            json!({ "prov:entity": iri, "prov:activity": activity_iri }),
+        );
+
+        for input in &attestation.predicate.inputs {
+            let input_iri = entity_iri(&input.name, &input.digest.sha256);
+            was_derived_from.insert(
+                format!("_:der_{}_{}", sanitize(&subject.name), sanitize(&input.name)),
+                json!({ "prov:generatedEntity": iri, "prov:usedEntity": input_iri }),
+            );
+        }
+    }
+
+    let mut activity_attrs = serde_json::Map::new();
+    activity_attrs.insert("makoto:transformType".to_string(), json!(attestation.predicate.transform.transform_type));
+    activity_attrs.insert("makoto:transformName".to_string(), json!(attestation.predicate.transform.name));
+
+    if let Some(metadata) = &attestation.predicate.metadata {
+        if let Some(started) = metadata.started_on {
+            activity_attrs.insert("prov:startedAtTime".to_string(), json!(started.to_rfc3339()));
+        }
+        if let Some(finished) = metadata.finished_on {
+            activity_attrs.insert("prov:endedAtTime".to_string(), json!(finished.to_rfc3339()));
+        }
+        if let Some(n) = metadata.records_input {
+            activity_attrs.insert("makoto:recordsInput".to_string(), json!(n));
+        }
+        if let Some(n) = metadata.records_output {
+            activity_attrs.insert("makoto:recordsOutput".to_string(), json!(n));
+        }
+    }
+
+    if let Some(params) = &attestation.predicate.transform.parameters {
+        for (key, value) in params {
+            activity_attrs.insert(format!("makoto:param:{}", key), value.clone());
+        }
+    }
+
+    let activities = json!({ activity_iri.clone(): Value::Object(activity_attrs) });
+    let agents = json!({ agent_iri.clone(): { "prov:type": "prov:Agent" } });
+
+    let was_associated_with = json!({
+        "_:assoc": { "prov:activity": activity_iri, "prov:agent": agent_iri }
+    });
+
+    json!({
+        "prefix": { "prov": PROV_NS, "makoto": MAKOTO_NS },
+        "entity": Value::Object(entities),
+        "activity": activities,
+        "agent": agents,
+        "used": Value::Object(used),
+        "wasGeneratedBy": Value::Object(was_generated_by),
+        "wasDerivedFrom": Value::Object(was_derived_from),
+        "wasAssociatedWith": was_associated_with,
+    })
+}
+
+/// Render the same PROV graph as RDF Turtle (PROV-O).
+pub fn to_prov_turtle(attestation: &TransformAttestation) -> String {
+    let activity_iri = activity_iri(attestation);
+    let agent_iri = &attestation.predicate.executor.id;
+
+    let mut out = String::new();
+    out.push_str(&format!("@prefix prov: <{}> .\n", PROV_NS));
+    out.push_str(&format!("@prefix makoto: <{}> .\n", MAKOTO_NS));
+    out.push_str("@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n\n");
+
+    out.push_str(&format!("<{}> a prov:Activity", activity_iri));
+    out.push_str(&format!(
+        " ;\n    makoto:transformType \"{}\"",
+        attestation.predicate.transform.transform_type
+    ));
+    out.push_str(&format!(
+        " ;\n    makoto:transformName \"{}\"",
+        attestation.predicate.transform.name
+    ));
+
+    if let Some(metadata) = &attestation.predicate.metadata {
+        if let Some(started) = metadata.started_on {
+            out.push_str(&format!(
+                " ;\n    prov:startedAtTime \"{}\"^^xsd:dateTime",
+                started.to_rfc3339()
+            ));
+        }
+        if let Some(finished) = metadata.finished_on {
+            out.push_str(&format!(
+                " ;\n    prov:endedAtTime \"{}\"^^xsd:dateTime",
+                finished.to_rfc3339()
+            ));
+        }
+    }
+
+    out.push_str(&format!(" ;\n    prov:wasAssociatedWith <{}>", agent_iri));
+
+    for input in &attestation.predicate.inputs {
+        out.push_str(&format!(
+            " ;\n    prov:used <{}>",
+            entity_iri(&input.name, &input.digest.sha256)
+        ));
+    }
+    out.push_str(" .\n\n");
+
+    out.push_str(&format!("<{}> a prov:Agent .\n\n", agent_iri));
+
+    for subject in &attestation.subject {
+        let subject_iri = entity_iri(&subject.name, &subject.digest.sha256);
+        out.push_str(&format!("<{}> a prov:Entity", subject_iri));
+        out.push_str(&format!(" ;\n    prov:wasGeneratedBy <{}>", activity_iri));
+        for input in &attestation.predicate.inputs {
+            out.push_str(&format!(
+                " ;\n    prov:wasDerivedFrom <{}>",
+                entity_iri(&input.name, &input.digest.sha256)
+            ));
+        }
+        out.push_str(" .\n\n");
+    }
+
+    for input in &attestation.predicate.inputs {
+        let input_iri = entity_iri(&input.name, &input.digest.sha256);
+        out.push_str(&format!("<{}> a prov:Entity .\n\n", input_iri));
+    }
+
+    out
+}
+
+fn activity_iri(attestation: &TransformAttestation) -> String {
+    let subject_hash = attestation
+        .subject
+        .first()
+        .map(|s| s.digest.sha256.as_str())
+        .unwrap_or("unknown");
+    format!("{}activity/{}", MAKOTO_NS, subject_hash)
+}
+
+fn entity_iri(name: &str, sha256: &str) -> String {
+    format!("{}entity/{}/{}", MAKOTO_NS, sanitize(name), sha256)
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+impl Dbom {
+    /// Export this DBOM's lineage as a W3C PROV-O JSON-LD document: every
+    /// [`crate::types::dbom::Source`] and the final
+    /// [`crate::types::dbom::DatasetInfo`] become a `prov:Entity`, every
+    /// [`crate::types::dbom::Transformation`] becomes a `prov:Activity`,
+    /// linked by `prov:used`, `prov:wasGeneratedBy`, and
+    /// `prov:wasDerivedFrom` edges. Lets lineage captured in the
+    /// Makoto-specific DBOM shape flow into generic PROV graph stores
+    /// rather than staying locked in this shape (the use case
+    /// `LineageGraphFormat::JsonLd` already names but nothing previously
+    /// produced).
+    pub fn to_prov_jsonld(&self) -> Value {
+        dbom_to_prov_jsonld(self)
+    }
+}
+
+/// Build the PROV-O JSON-LD document for a [`Dbom`]. See [`Dbom::to_prov_jsonld`].
+pub fn dbom_to_prov_jsonld(dbom: &Dbom) -> Value {
+    let mut graph = Vec::new();
+
+    let dataset_urn = dbom_entity_urn(&dbom.dbom_id, &dbom.dataset.name);
+    let mut dataset_node = serde_json::Map::new();
+    dataset_node.insert("@id".to_string(), json!(dataset_urn));
+    dataset_node.insert("@type".to_string(), json!("prov:Entity"));
+    dataset_node.insert("makotoLevel".to_string(), json!(dbom.dataset.makoto_level));
+    dataset_node.insert(
+        "prov:wasDerivedFrom".to_string(),
+        json!(dbom
+            .sources
+            .iter()
+            .map(|source| dbom_entity_urn(&dbom.dbom_id, &source.name))
+            .collect::<Vec<_>>()),
+    );
+    if let Some(compliance) = &dbom.compliance {
+        dataset_node.insert("compliance".to_string(), json!(compliance));
+    }
+    graph.push(Value::Object(dataset_node));
+
+    for source in &dbom.sources {
+        let mut node = serde_json::Map::new();
+        node.insert("@id".to_string(), json!(dbom_entity_urn(&dbom.dbom_id, &source.name)));
+        node.insert("@type".to_string(), json!("prov:Entity"));
+        node.insert("makotoLevel".to_string(), json!(source.makoto_level));
+        if let Some(attestation_ref) = &source.attestation_ref {
+            node.insert("attestationRef".to_string(), json!(attestation_ref));
+        }
+        graph.push(Value::Object(node));
+    }
+
+    for transformation in dbom.transformations.iter().flatten() {
+        let activity_urn = dbom_activity_urn(&dbom.dbom_id, transformation.order, &transformation.name);
+
+        let mut activity_node = serde_json::Map::new();
+        activity_node.insert("@id".to_string(), json!(activity_urn));
+        activity_node.insert("@type".to_string(), json!("prov:Activity"));
+        activity_node.insert("makotoLevel".to_string(), json!(transformation.makoto_level));
+        if let Some(attestation_ref) = &transformation.attestation_ref {
+            activity_node.insert("attestationRef".to_string(), json!(attestation_ref));
+        }
+        activity_node.insert(
+            "prov:used".to_string(),
+            json!(transformation
+                .inputs
+                .iter()
+                .map(|name| dbom_entity_urn(&dbom.dbom_id, name))
+                .collect::<Vec<_>>()),
+        );
+        graph.push(Value::Object(activity_node));
+
+        for output in &transformation.outputs {
+            let mut generated_node = serde_json::Map::new();
+            generated_node.insert("@id".to_string(), json!(dbom_entity_urn(&dbom.dbom_id, output)));
+            generated_node.insert("@type".to_string(), json!("prov:Entity"));
+            generated_node.insert("prov:wasGeneratedBy".to_string(), json!(activity_urn));
+            graph.push(Value::Object(generated_node));
+        }
+    }
+
+    json!({
+        "@context": {
+            "prov": PROV_NS,
+            "makoto": MAKOTO_NS,
+            "makotoLevel": "makoto:makotoLevel",
+            "attestationRef": "makoto:attestationRef",
+            "compliance": "makoto:compliance",
+        },
+        "@graph": graph,
+    })
+}
+
+/// URN for a PROV entity node, namespaced under the DBOM's own `dbom_id` so
+/// entities from different DBOMs never collide.
+fn dbom_entity_urn(dbom_id: &str, name: &str) -> String {
+    format!("{}:entity:{}", dbom_id, sanitize(name))
+}
+
+/// URN for a PROV activity node representing one [`crate::types::dbom::Transformation`].
+fn dbom_activity_urn(dbom_id: &str, order: u32, name: &str) -> String {
+    format!("{}:activity:{}:{}", dbom_id, order, sanitize(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::common::IsolationLevel;
+    use crate::types::transform::{Executor, InputReference, TransformDefinition};
+    use crate::types::dbom::{DatasetInfo, DbomDigest, Source, Transformation};
+    use crate::types::{Digest, MakotoLevel, Subject};
+    use chrono::Utc;
+
+    fn sample_dbom() -> Dbom {
+        let dataset = DatasetInfo::new(
+            "fraud-detection-training",
+            "1.0.0",
+            Utc::now(),
+            DbomDigest::new("a".repeat(64)),
+            MakotoLevel::L2,
+        );
+        let source = Source::new(
+            "customer_transactions",
+            "https://makoto.dev/origin/v1",
+            MakotoLevel::L2,
+        );
+        let transformation = Transformation::new(
+            1,
+            "dedupe",
+            "https://makoto.dev/transform/v1",
+            MakotoLevel::L2,
+            vec!["customer_transactions".to_string()],
+            vec!["fraud-detection-training".to_string()],
+        );
+
+        Dbom::builder()
+            .id("urn:dbom:example.com:fraud-detection-v1")
+            .dataset(dataset)
+            .source(source)
+            .transformation(transformation)
+            .build()
+            .unwrap()
+    }
+
+    fn sample_attestation() -> TransformAttestation {
+        let input = InputReference::new("dataset:input", Digest::new("a".repeat(64)));
+        let transform = TransformDefinition::new("https://makoto.dev/transforms/filter", "Filter");
+        let executor = Executor::new("https://expanso.io/executors/001").with_isolation(IsolationLevel::Container);
+
+        TransformAttestation::builder()
+            .subject(Subject::new("dataset:output", Digest::new("b".repeat(64))))
+            .input(input)
+            .transform(transform)
+            .executor(executor)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_prov_json_has_expected_relations() {
+        let attestation = sample_attestation();
+        let doc = to_prov_json(&attestation);
+
+        assert!(doc["entity"].as_object().unwrap().len() == 2);
+        assert_eq!(doc["used"].as_object().unwrap().len(), 1);
+        assert_eq!(doc["wasGeneratedBy"].as_object().unwrap().len(), 1);
+        assert_eq!(doc["wasDerivedFrom"].as_object().unwrap().len(), 1);
+        assert_eq!(doc["wasAssociatedWith"]["_:assoc"]["prov:agent"], json!(attestation.predicate.executor.id));
+    }
+
+    #[test]
+    fn test_prov_turtle_contains_core_triples() {
+        let turtle = to_prov_turtle(&sample_attestation());
+
+        assert!(turtle.contains("a prov:Activity"));
+        assert!(turtle.contains("a prov:Agent"));
+        assert!(turtle.contains("prov:wasGeneratedBy"));
+        assert!(turtle.contains("prov:wasDerivedFrom"));
+        assert!(turtle.contains("prov:used"));
+    }
+
+    #[test]
+    fn test_dbom_to_prov_jsonld_has_context_and_graph() {
+        let doc = sample_dbom().to_prov_jsonld();
+
+        assert_eq!(doc["@context"]["prov"], json!(PROV_NS));
+        assert_eq!(doc["@context"]["makoto"], json!(MAKOTO_NS));
+        assert!(doc["@graph"].as_array().unwrap().len() >= 3);
+    }
+
+    #[test]
+    fn test_dbom_to_prov_jsonld_links_source_transformation_and_dataset() {
+        let dbom = sample_dbom();
+        let doc = dbom.to_prov_jsonld();
+        let graph = doc["@graph"].as_array().unwrap();
+
+        let dataset_urn = dbom_entity_urn(&dbom.dbom_id, &dbom.dataset.name);
+        let source_urn = dbom_entity_urn(&dbom.dbom_id, "customer_transactions");
+        let activity_urn = dbom_activity_urn(&dbom.dbom_id, 1, "dedupe");
+
+        let dataset_node = graph.iter().find(|n| n["@id"] == json!(dataset_urn)).unwrap();
+        assert_eq!(dataset_node["@type"], json!("prov:Entity"));
+        assert_eq!(dataset_node["prov:wasDerivedFrom"], json!([source_urn.clone()]));
+
+        let activity_node = graph.iter().find(|n| n["@id"] == json!(activity_urn)).unwrap();
+        assert_eq!(activity_node["@type"], json!("prov:Activity"));
+        assert_eq!(activity_node["prov:used"], json!([source_urn]));
+
+        let generated_node = graph
+            .iter()
+            .find(|n| n["@id"] == json!(dataset_urn) && n["prov:wasGeneratedBy"] == json!(activity_urn));
+        assert!(generated_node.is_some());
+    }
+}
@@ -0,0 +1,294 @@
+//! Provenance graph resolution and lineage verification.
+//!
+//! Walks the `attestation_ref` links carried by `InputReference` to build a
+//! directed lineage DAG rooted at a single `TransformAttestation`, then
+//! verifies that the chain is well-formed and computes the effective
+//! (weakest-link) Makoto level across the whole closure.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{MakotoError, Result};
+use crate::types::{Digest, MakotoLevel, TransformAttestation};
+
+/// Resolves an attestation reference URI to the attestation it points to.
+///
+/// Implementations typically fetch from an HTTP endpoint, a local store, or
+/// an in-memory cache; this crate only defines the contract.
+pub trait AttestationResolver {
+    /// Fetch the transform attestation referenced by `uri`.
+    fn resolve(&self, uri: &str) -> Result<TransformAttestation>;
+}
+
+/// A node in the provenance graph: one resolved attestation and the digests
+/// it claims to produce.
+#[derive(Debug, Clone)]
+pub struct ProvenanceNode {
+    /// URI used to resolve this node (the root node has no ref, so `None`).
+    pub attestation_ref: Option<String>,
+    /// The attestation itself.
+    pub attestation: TransformAttestation,
+}
+
+/// A directed lineage DAG keyed by subject digest.
+#[derive(Debug, Clone, Default)]
+pub struct ProvenanceGraph {
+    /// Nodes keyed by the SHA-256 of a subject digest they produce.
+    nodes: HashMap<String, ProvenanceNode>,
+    /// Refs that were listed as inputs but could not be resolved.
+    unresolved: Vec<String>,
+}
+
+impl ProvenanceGraph {
+    /// Resolve the full lineage DAG starting from `root`, using `resolver`
+    /// to follow `InputReference::attestation_ref` links.
+    pub fn resolve(root: TransformAttestation, resolver: &dyn AttestationResolver) -> Result<Self> {
+        let mut graph = ProvenanceGraph::default();
+        let mut stack = HashSet::new();
+
+        graph.visit(root, None, resolver, &mut stack)?;
+        Ok(graph)
+    }
+
+    fn visit(
+        &mut self,
+        attestation: TransformAttestation,
+        attestation_ref: Option<String>,
+        resolver: &dyn AttestationResolver,
+        stack: &mut HashSet<String>,
+    ) -> Result<()> {
+        for subject in &attestation.subject {
+            let key = subject.digest.sha256.clone();
+
+            if stack.contains(&key) {
+                return Err(MakotoError::ChainError(format!(
+                    "cycle detected: digest {} reappears on the current traversal stack",
+                    key
+                )));
+            }
+
+            if self.nodes.contains_key(&key) {
+                // Already resolved via another path; shared ancestor, not a cycle.
+                continue;
+            }
+
+            stack.insert(key.clone());
+
+            for input in &attestation.predicate.inputs {
+                self.resolve_input(input, resolver, stack)?;
+            }
+
+            stack.remove(&key);
+        }
+
+        for subject in &attestation.subject {
+            let key = subject.digest.sha256.clone();
+            self.nodes.entry(key).or_insert_with(|| ProvenanceNode {
+                attestation_ref: attestation_ref.clone(),
+                attestation: attestation.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn resolve_input(
+        &mut self,
+        input: &crate::types::transform::InputReference,
+        resolver: &dyn AttestationResolver,
+        stack: &mut HashSet<String>,
+    ) -> Result<()> {
+        let Some(uri) = &input.attestation_ref else {
+            // No ref to follow: a root input, not an unresolved one.
+            return Ok(());
+        };
+
+        if self.nodes.contains_key(&input.digest.sha256) {
+            return Ok(());
+        }
+
+        let parent = match resolver.resolve(uri) {
+            Ok(a) => a,
+            Err(_) => {
+                self.unresolved.push(uri.clone());
+                return Ok(());
+            }
+        };
+
+        if !parent
+            .subject
+            .iter()
+            .any(|s| digests_match(&s.digest, &input.digest))
+        {
+            return Err(MakotoError::ChainError(format!(
+                "input '{}' digest does not match any subject of the attestation at {}",
+                input.name, uri
+            )));
+        }
+
+        self.visit(parent, Some(uri.clone()), resolver, stack)
+    }
+
+    /// Verify the resolved graph, returning the effective Makoto level (the
+    /// minimum across every node) and the set of refs that couldn't be
+    /// resolved.
+    pub fn verify(&self) -> ProvenanceVerification {
+        let min_level = self
+            .nodes
+            .values()
+            .flat_map(|node| {
+                node.attestation
+                    .predicate
+                    .inputs
+                    .iter()
+                    .filter_map(|i| i.makoto_level)
+            })
+            .min();
+
+        ProvenanceVerification {
+            effective_level: min_level,
+            unresolved: self.unresolved.clone(),
+        }
+    }
+
+    /// Number of resolved nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+fn digests_match(a: &Digest, b: &Digest) -> bool {
+    a.sha256.eq_ignore_ascii_case(&b.sha256)
+}
+
+/// Result of verifying a `ProvenanceGraph`.
+#[derive(Debug, Clone)]
+pub struct ProvenanceVerification {
+    /// Weakest Makoto level found among the inputs in the closure, or `None`
+    /// if no input carried a level.
+    pub effective_level: Option<MakotoLevel>,
+    /// Attestation refs that were listed as inputs but could not be resolved.
+    pub unresolved: Vec<String>,
+}
+
+impl ProvenanceVerification {
+    /// Whether every referenced input was resolved.
+    pub fn is_complete(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::common::IsolationLevel;
+    use crate::types::transform::{Executor, InputReference, TransformDefinition};
+    use crate::types::Subject;
+    use std::cell::RefCell;
+
+    struct MapResolver(RefCell<HashMap<String, TransformAttestation>>);
+
+    impl AttestationResolver for MapResolver {
+        fn resolve(&self, uri: &str) -> Result<TransformAttestation> {
+            self.0
+                .borrow()
+                .get(uri)
+                .cloned()
+                .ok_or_else(|| MakotoError::ChainError(format!("no attestation at {}", uri)))
+        }
+    }
+
+    /// Builds a `TransformAttestation` over `inputs`, falling back to a
+    /// single unresolvable leaf input (no `attestation_ref`, so
+    /// `ProvenanceGraph::resolve` treats it as a root and doesn't try to
+    /// follow it) when the caller has none of its own — `build()` requires
+    /// at least one input.
+    fn transform(subject_hash: &str, inputs: Vec<InputReference>) -> TransformAttestation {
+        let inputs = if inputs.is_empty() {
+            vec![InputReference::new(
+                "dataset:raw",
+                Digest::new("0".repeat(64)),
+            )]
+        } else {
+            inputs
+        };
+
+        let mut builder = TransformAttestation::builder()
+            .subject(Subject::new("dataset:out", Digest::new(subject_hash.to_string())))
+            .transform(TransformDefinition::new(
+                "https://makoto.dev/transforms/noop",
+                "Noop",
+            ))
+            .executor(Executor::new("exec-1").with_isolation(IsolationLevel::Process));
+
+        for input in inputs {
+            builder = builder.input(input);
+        }
+
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_resolves_linear_chain_and_computes_min_level() {
+        let root_hash = "a".repeat(64);
+        let parent_hash = "b".repeat(64);
+
+        let parent = transform(&parent_hash, vec![]);
+
+        let root = transform(
+            &root_hash,
+            vec![InputReference::new(
+                "dataset:parent",
+                Digest::new(parent_hash.clone()),
+            )
+            .with_makoto_level(MakotoLevel::L1)
+            .with_attestation_ref("https://example.com/parent")],
+        );
+
+        let resolver = MapResolver(RefCell::new(HashMap::from([(
+            "https://example.com/parent".to_string(),
+            parent,
+        )])));
+
+        let graph = ProvenanceGraph::resolve(root, &resolver).unwrap();
+        let result = graph.verify();
+
+        assert!(result.is_complete());
+        assert_eq!(result.effective_level, Some(MakotoLevel::L1));
+        assert_eq!(graph.node_count(), 2);
+    }
+
+    #[test]
+    fn test_unresolved_ref_is_reported_not_fatal() {
+        let root = transform(
+            &"a".repeat(64),
+            vec![InputReference::new("dataset:missing", Digest::new("c".repeat(64)))
+                .with_attestation_ref("https://example.com/missing")],
+        );
+
+        let resolver = MapResolver(RefCell::new(HashMap::new()));
+        let graph = ProvenanceGraph::resolve(root, &resolver).unwrap();
+        let result = graph.verify();
+
+        assert!(!result.is_complete());
+        assert_eq!(result.unresolved, vec!["https://example.com/missing".to_string()]);
+    }
+
+    #[test]
+    fn test_mismatched_digest_is_rejected() {
+        let parent_hash = "b".repeat(64);
+        let parent = transform(&parent_hash, vec![]);
+
+        let root = transform(
+            &"a".repeat(64),
+            vec![InputReference::new("dataset:parent", Digest::new("d".repeat(64)))
+                .with_attestation_ref("https://example.com/parent")],
+        );
+
+        let resolver = MapResolver(RefCell::new(HashMap::from([(
+            "https://example.com/parent".to_string(),
+            parent,
+        )])));
+
+        assert!(ProvenanceGraph::resolve(root, &resolver).is_err());
+    }
+}
@@ -4,6 +4,7 @@
 
 use crate::error::{MakotoError, Result};
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, Utc};
 use p256::ecdsa::{
     signature::{Signer, Verifier},
     Signature, SigningKey, VerifyingKey,
@@ -11,6 +12,102 @@ use p256::ecdsa::{
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
 
+/// Label for the only signing algorithm this SDK produces (ECDSA P-256),
+/// matching the `"alg"` value [`crate::credential`]'s VC-JWTs use for the
+/// same key type. Checked against a [`crate::verification::VerificationPolicy`]'s
+/// `allowed_algorithms` allow-list.
+pub const SIGNATURE_ALGORITHM: &str = "ES256";
+
+/// Signature algorithm identifiers, analogous to [`crate::types::HashAlgorithm`].
+///
+/// This SDK's signer/verifier only implement [`SignatureAlgorithm::EcdsaP256`]
+/// — `Ed25519` and `RsaPkcs1Sha256` are recognized labels, not supported
+/// backends. They exist so an envelope produced by another implementation
+/// round-trips through this crate (deserializes, carries its declared
+/// algorithm) and reports which backend it actually needs, rather than
+/// failing to parse. Dispatching to either is an error: this crate has no
+/// Ed25519/RSA dependency to verify against, and hand-rolling either
+/// algorithm instead of using an audited implementation would be worse than
+/// refusing. [`SignedAttestation::verify`] returns
+/// [`MakotoError::UnsupportedAlgorithm`] rather than silently mis-verifying
+/// if it ever sees one of these labels. A deployment that needs Ed25519 or
+/// RSA interop should add the corresponding crate (`ed25519-dalek`, `rsa`)
+/// and a backend behind a feature flag, the same way `arrow`/`docker`/`otel`
+/// gate their optional dependencies in this crate. Until then, this enum by
+/// itself does **not** deliver Ed25519 or RSA interop — callers that need
+/// either should treat support as not implemented, not as a parsing-only
+/// gap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureAlgorithm {
+    EcdsaP256,
+    Ed25519,
+    RsaPkcs1Sha256,
+}
+
+impl SignatureAlgorithm {
+    /// The wire label used in [`AttestationSignature::algorithm`] and
+    /// [`VerifierSet`]/JWKS `alg` fields (JWA-style, matching
+    /// [`SIGNATURE_ALGORITHM`] for [`Self::EcdsaP256`]).
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::EcdsaP256 => SIGNATURE_ALGORITHM,
+            Self::Ed25519 => "EdDSA",
+            Self::RsaPkcs1Sha256 => "RS256",
+        }
+    }
+
+    /// Parse a wire label back into a [`SignatureAlgorithm`].
+    pub fn from_label(label: &str) -> Result<Self> {
+        match label {
+            SIGNATURE_ALGORITHM => Ok(Self::EcdsaP256),
+            "EdDSA" => Ok(Self::Ed25519),
+            "RS256" => Ok(Self::RsaPkcs1Sha256),
+            other => Err(MakotoError::UnsupportedAlgorithm(other.to_string())),
+        }
+    }
+
+    /// Error out unless this is [`Self::EcdsaP256`] — the only algorithm
+    /// this crate actually has a verification backend for.
+    fn require_ecdsa_p256(self) -> Result<()> {
+        match self {
+            Self::EcdsaP256 => Ok(()),
+            other => Err(MakotoError::UnsupportedAlgorithm(format!(
+                "this SDK has no {} verification backend",
+                other.label()
+            ))),
+        }
+    }
+
+    /// Binary tag for [`MakotoSigner::to_bytes`]/[`MakotoSigner::from_bytes`],
+    /// analogous to `hash::algorithm_to_tag` for [`crate::hash::MerkleProof`].
+    fn to_tag(self) -> u8 {
+        match self {
+            Self::EcdsaP256 => 0,
+            Self::Ed25519 => 1,
+            Self::RsaPkcs1Sha256 => 2,
+        }
+    }
+
+    /// Parse a [`Self::to_tag`] value back into a [`SignatureAlgorithm`].
+    fn from_tag(tag: u8) -> Result<Self> {
+        match tag {
+            0 => Ok(Self::EcdsaP256),
+            1 => Ok(Self::Ed25519),
+            2 => Ok(Self::RsaPkcs1Sha256),
+            other => Err(MakotoError::KeyError(format!(
+                "unknown signature algorithm tag {other}"
+            ))),
+        }
+    }
+}
+
+impl Default for SignatureAlgorithm {
+    fn default() -> Self {
+        Self::EcdsaP256
+    }
+}
+
 /// A signed attestation envelope (DSSE format).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -23,21 +120,40 @@ pub struct SignedAttestation {
 
     /// Signatures over the payload.
     pub signatures: Vec<AttestationSignature>,
+
+    /// Envelope-level claims (issuer/audience/expiry), checked by
+    /// [`crate::verification::verify_signed_attestation_with_policy`]
+    /// independently of the payload — DSSE itself has no builtin claims,
+    /// unlike a JWT.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub claims: Option<AttestationClaims>,
+
+    /// Transparency-log entries this envelope was recorded under, if any —
+    /// lets a verifier confirm public recording (inclusion proof valid
+    /// against a trusted log checkpoint, see
+    /// [`crate::verification::verify_transparency_inclusion`]) in addition
+    /// to signature validity. A collector can't later deny having produced
+    /// an attestation once it carries an entry from a log it doesn't
+    /// control. More than one entry supports submitting to several
+    /// independent logs (cf. Certificate Transparency's multi-log
+    /// requirement).
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub log_entries: Option<Vec<crate::transparency::LogEntry>>,
 }
 
 impl SignedAttestation {
     /// Create a signed attestation from a payload.
+    ///
+    /// The payload is serialized via [`crate::types::to_canonical_json`]
+    /// (RFC 8785 JCS) rather than `serde_json`'s default output, so the
+    /// exact bytes that get signed don't depend on field insertion order or
+    /// number formatting.
     pub fn sign<T: Serialize>(attestation: &T, signer: &MakotoSigner) -> Result<Self> {
-        let payload_json = serde_json::to_string(attestation)?;
+        let payload_json = crate::types::to_canonical_json(attestation)?;
         let payload_b64 = BASE64.encode(payload_json.as_bytes());
 
-        // DSSE signing: sign "DSSEv1 <payloadType> <payload>"
-        let pae = format!(
-            "DSSEv1 {} {}",
-            "application/vnd.in-toto+json", payload_b64
-        );
-
-        let signature = signer.sign(pae.as_bytes())?;
+        let pae = pre_auth_encoding("application/vnd.in-toto+json", payload_json.as_bytes());
+        let signature = signer.sign(&pae)?;
 
         Ok(Self {
             payload_type: "application/vnd.in-toto+json".to_string(),
@@ -45,10 +161,101 @@ impl SignedAttestation {
             signatures: vec![AttestationSignature {
                 keyid: signer.key_id().to_string(),
                 sig: BASE64.encode(signature.to_bytes()),
+                algorithm: Some(SIGNATURE_ALGORITHM.to_string()),
             }],
+            claims: None,
+            log_entries: None,
         })
     }
 
+    /// Attach envelope-level claims to be checked by a
+    /// [`crate::verification::VerificationPolicy`].
+    pub fn with_claims(mut self, claims: AttestationClaims) -> Self {
+        self.claims = Some(claims);
+        self
+    }
+
+    /// Record that this envelope was submitted to a transparency log,
+    /// carrying the resulting [`crate::transparency::LogEntry`] along with
+    /// it. Call once per log if submitting to more than one.
+    pub fn with_log_entry(mut self, entry: crate::transparency::LogEntry) -> Self {
+        self.log_entries.get_or_insert_with(Vec::new).push(entry);
+        self
+    }
+
+    /// Co-sign this envelope with an additional `signer`, over the same
+    /// payload the first signature covers — for provenance chains signed by
+    /// more than one party (e.g. collector + platform + notary), checked
+    /// with [`crate::verification::verify_threshold`].
+    pub fn add_signature(&mut self, signer: &MakotoSigner) -> Result<()> {
+        let payload_bytes = BASE64
+            .decode(&self.payload)
+            .map_err(|e| MakotoError::Signature(format!("Invalid payload base64: {}", e)))?;
+        let pae = pre_auth_encoding(&self.payload_type, &payload_bytes);
+        let signature = signer.sign(&pae)?;
+
+        self.signatures.push(AttestationSignature {
+            keyid: signer.key_id().to_string(),
+            sig: BASE64.encode(signature.to_bytes()),
+            algorithm: Some(SIGNATURE_ALGORITHM.to_string()),
+        });
+
+        Ok(())
+    }
+
+    /// Verify against a whole [`MakotoKeyring`] rather than a single
+    /// [`MakotoVerifier`], for relying parties that pin an org's key set
+    /// without already knowing which key in it signed this envelope (e.g.
+    /// attestations produced by a rotating fleet of collectors).
+    ///
+    /// Returns the `key_id` of the first signature that validates against a
+    /// key in `keyring`, or `Ok(None)` if no signature's `keyid` is present
+    /// in the keyring or none validate.
+    pub fn verify_with_keyring(&self, keyring: &MakotoKeyring) -> Result<Option<VerifiedKeyId>> {
+        self.verify_with_keyring_at(keyring, Utc::now())
+    }
+
+    /// Same as [`Self::verify_with_keyring`], but checks each candidate
+    /// key's validity window against `reference_time` instead of the
+    /// current time — for relying parties replaying or backdating
+    /// verification (e.g. "was this valid when it was logged?").
+    ///
+    /// A signature from a key outside its validity window at
+    /// `reference_time` fails closed with [`MakotoError::KeyExpired`] rather
+    /// than being silently skipped, so a caller can tell "no matching key"
+    /// apart from "matching key, but retired".
+    pub fn verify_with_keyring_at(
+        &self,
+        keyring: &MakotoKeyring,
+        reference_time: DateTime<Utc>,
+    ) -> Result<Option<VerifiedKeyId>> {
+        let payload_bytes = BASE64
+            .decode(&self.payload)
+            .map_err(|e| MakotoError::Signature(format!("Invalid payload base64: {}", e)))?;
+        let pae = pre_auth_encoding(&self.payload_type, &payload_bytes);
+
+        for sig in &self.signatures {
+            let verifier = match keyring.get(&sig.keyid) {
+                Some(v) => v,
+                None => continue,
+            };
+            sig.declared_algorithm()?.require_ecdsa_p256()?;
+            verifier.check_validity_at(reference_time)?;
+
+            let sig_bytes = BASE64
+                .decode(&sig.sig)
+                .map_err(|e| MakotoError::Signature(format!("Invalid signature base64: {}", e)))?;
+            let signature = Signature::from_slice(&sig_bytes)
+                .map_err(|e| MakotoError::Signature(format!("Invalid signature format: {}", e)))?;
+
+            if verifier.verify(&pae, &signature)? {
+                return Ok(Some(VerifiedKeyId(sig.keyid.clone())));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Get the decoded payload.
     pub fn decode_payload<T: for<'de> Deserialize<'de>>(&self) -> Result<T> {
         let payload_bytes = BASE64
@@ -58,17 +265,37 @@ impl SignedAttestation {
         serde_json::from_slice(&payload_bytes).map_err(MakotoError::from)
     }
 
-    /// Verify all signatures.
+    /// Verify all signatures against the current time — see [`Self::verify_at`].
     ///
     /// Returns `Ok(true)` if at least one signature from the verifier's key is valid.
     /// Returns `Ok(false)` if no matching key is found or signature verification fails.
     pub fn verify(&self, verifier: &MakotoVerifier) -> Result<bool> {
-        let pae = format!("DSSEv1 {} {}", self.payload_type, self.payload);
+        self.verify_at(verifier, Utc::now())
+    }
+
+    /// Same as [`Self::verify`], but checks `verifier`'s validity window
+    /// against `reference_time` instead of the current time — for relying
+    /// parties replaying or backdating verification (e.g. "was this valid
+    /// when it was logged?").
+    ///
+    /// Unlike a missing key or a bad signature (which fail closed as
+    /// `Ok(false)`), a key found outside its validity window at
+    /// `reference_time` returns [`MakotoError::KeyExpired`]: the attestation
+    /// was signed by a key relevant to this envelope, but one the caller's
+    /// rotation policy no longer trusts at that point in time, which is
+    /// worth distinguishing from "this key never signed this envelope".
+    pub fn verify_at(&self, verifier: &MakotoVerifier, reference_time: DateTime<Utc>) -> Result<bool> {
+        let payload_bytes = BASE64
+            .decode(&self.payload)
+            .map_err(|e| MakotoError::Signature(format!("Invalid payload base64: {}", e)))?;
+        let pae = pre_auth_encoding(&self.payload_type, &payload_bytes);
         let mut found_matching_key = false;
 
         for sig in &self.signatures {
             if sig.keyid == verifier.key_id() {
                 found_matching_key = true;
+                sig.declared_algorithm()?.require_ecdsa_p256()?;
+                verifier.check_validity_at(reference_time)?;
 
                 let sig_bytes = BASE64
                     .decode(&sig.sig)
@@ -77,7 +304,7 @@ impl SignedAttestation {
                 let signature = Signature::from_slice(&sig_bytes)
                     .map_err(|e| MakotoError::Signature(format!("Invalid signature format: {}", e)))?;
 
-                if !verifier.verify(pae.as_bytes(), &signature)? {
+                if !verifier.verify(&pae, &signature)? {
                     return Ok(false);
                 }
             }
@@ -88,6 +315,27 @@ impl SignedAttestation {
     }
 }
 
+/// Compute the DSSE Pre-Auth Encoding (PAE) for `payload_type`/`payload`:
+/// `"DSSEv1" SP len(payloadType) SP payloadType SP len(payload) SP payload`,
+/// where `SP` is a single `0x20` byte and the two lengths are ASCII-decimal
+/// byte counts. `payload` must be the *raw* attestation bytes, not the
+/// base64 form stored on [`SignedAttestation::payload`] — binding both the
+/// exact payload type string and the exact payload bytes this way is what
+/// lets any standard DSSE verifier (not just this SDK) check the signature
+/// without canonicalization ambiguity.
+fn pre_auth_encoding(payload_type: &str, payload: &[u8]) -> Vec<u8> {
+    let mut pae = Vec::with_capacity(payload_type.len() + payload.len() + 32);
+    pae.extend_from_slice(b"DSSEv1 ");
+    pae.extend_from_slice(payload_type.len().to_string().as_bytes());
+    pae.push(b' ');
+    pae.extend_from_slice(payload_type.as_bytes());
+    pae.push(b' ');
+    pae.extend_from_slice(payload.len().to_string().as_bytes());
+    pae.push(b' ');
+    pae.extend_from_slice(payload);
+    pae
+}
+
 /// A single signature in an attestation.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttestationSignature {
@@ -96,6 +344,218 @@ pub struct AttestationSignature {
 
     /// Base64-encoded signature.
     pub sig: String,
+
+    /// Algorithm this signature was produced with (e.g. [`SIGNATURE_ALGORITHM`]).
+    /// Absent on envelopes signed before this field existed; callers that
+    /// care (like [`crate::verification::verify_signed_attestation_multi`])
+    /// should treat a missing value as [`SIGNATURE_ALGORITHM`].
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub algorithm: Option<String>,
+}
+
+impl AttestationSignature {
+    /// Parse [`Self::algorithm`], defaulting to [`SignatureAlgorithm::EcdsaP256`]
+    /// for envelopes signed before this field existed.
+    pub fn declared_algorithm(&self) -> Result<SignatureAlgorithm> {
+        match self.algorithm.as_deref() {
+            Some(label) => SignatureAlgorithm::from_label(label),
+            None => Ok(SignatureAlgorithm::EcdsaP256),
+        }
+    }
+}
+
+/// Envelope-level claims attached to a [`SignedAttestation`], checked
+/// against a [`crate::verification::VerificationPolicy`] independently of
+/// the attestation payload — borrows the shape of a JWT's `iss`/`aud`/`nbf`/
+/// `exp` claims.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttestationClaims {
+    /// Who issued this attestation (e.g. a collector or organization id).
+    pub issuer: String,
+
+    /// Who the attestation is scoped to, if restricted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+
+    /// Not valid before this time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<DateTime<Utc>>,
+
+    /// Not valid after this time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl AttestationClaims {
+    /// Create claims with just an issuer; `with_*` setters add the rest.
+    pub fn new(issuer: impl Into<String>) -> Self {
+        Self {
+            issuer: issuer.into(),
+            audience: None,
+            not_before: None,
+            expires_at: None,
+        }
+    }
+
+    /// Set the expected audience.
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = Some(audience.into());
+        self
+    }
+
+    /// Set the not-before time.
+    pub fn with_not_before(mut self, not_before: DateTime<Utc>) -> Self {
+        self.not_before = Some(not_before);
+        self
+    }
+
+    /// Set the expiry time.
+    pub fn with_expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+}
+
+/// A signed tree head from an append-only transparency log (e.g. a
+/// Sigstore Rekor-style log), attesting the log's size and root hash at a
+/// point in time.
+///
+/// Verified independently of an attestation's own signer via
+/// [`crate::verification::verify_transparency_inclusion`], so a log
+/// operator can't silently omit or reorder entries without the checkpoint's
+/// signature no longer matching its claimed root.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogCheckpoint {
+    /// Identifies which log produced this checkpoint (e.g. a log URL).
+    pub origin: String,
+
+    /// Number of entries in the log at this checkpoint.
+    pub tree_size: u64,
+
+    /// Hex-encoded root hash of the log's Merkle tree at this checkpoint.
+    pub root_hash: String,
+
+    /// Base64-encoded ECDSA P-256 signature over this checkpoint's
+    /// canonical body, from the log's signing key.
+    pub signature: String,
+}
+
+impl LogCheckpoint {
+    /// Sign a new checkpoint with the log's key.
+    pub fn sign(
+        origin: impl Into<String>,
+        tree_size: u64,
+        root_hash: impl Into<String>,
+        signer: &MakotoSigner,
+    ) -> Result<Self> {
+        let origin = origin.into();
+        let root_hash = root_hash.into();
+        let signature = signer.sign(Self::canonical_body(&origin, tree_size, &root_hash).as_bytes())?;
+
+        Ok(Self {
+            origin,
+            tree_size,
+            root_hash,
+            signature: BASE64.encode(signature.to_bytes()),
+        })
+    }
+
+    /// Verify this checkpoint's signature was produced by `verifier`'s key.
+    pub fn verify_signature(&self, verifier: &MakotoVerifier) -> Result<bool> {
+        let body = Self::canonical_body(&self.origin, self.tree_size, &self.root_hash);
+
+        let sig_bytes = BASE64
+            .decode(&self.signature)
+            .map_err(|e| MakotoError::Signature(format!("Invalid checkpoint signature base64: {}", e)))?;
+
+        let signature = Signature::from_slice(&sig_bytes)
+            .map_err(|e| MakotoError::Signature(format!("Invalid checkpoint signature format: {}", e)))?;
+
+        verifier.verify(body.as_bytes(), &signature)
+    }
+
+    fn canonical_body(origin: &str, tree_size: u64, root_hash: &str) -> String {
+        format!("{origin}\n{tree_size}\n{root_hash}")
+    }
+}
+
+/// An inclusion proof for one entry in a transparency log's Merkle tree
+/// (Sigstore Rekor-style): the leaf's index, the tree size the proof was
+/// generated against, and the ordered sibling hashes (the audit path) from
+/// leaf to root.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionProof {
+    /// Index of the leaf in the log, 0-based.
+    pub leaf_index: u64,
+
+    /// Size of the log's tree this proof was generated against.
+    pub tree_size: u64,
+
+    /// Hex-encoded sibling hashes, ordered leaf to root.
+    pub audit_path: Vec<String>,
+
+    /// When the log accepted this entry, per the log's own clock — a
+    /// keyless (see [`crate::keyless`]) verifier uses this as the
+    /// signing-time timestamp to check an ephemeral [`crate::keyless::Certificate`]
+    /// was still valid, since the signer's own clock can't be trusted and
+    /// the certificate is typically expired by the time anyone verifies it.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub integrated_time: Option<DateTime<Utc>>,
+}
+
+impl InclusionProof {
+    /// Create a new inclusion proof with no integrated timestamp; add one
+    /// with [`Self::with_integrated_time`].
+    pub fn new(leaf_index: u64, tree_size: u64, audit_path: Vec<String>) -> Self {
+        Self {
+            leaf_index,
+            tree_size,
+            audit_path,
+            integrated_time: None,
+        }
+    }
+
+    /// Record when the log accepted this entry.
+    pub fn with_integrated_time(mut self, integrated_time: DateTime<Utc>) -> Self {
+        self.integrated_time = Some(integrated_time);
+        self
+    }
+}
+
+/// A transparency-log entry binding a [`SignedAttestation`] to the
+/// append-only log it was recorded in: its position in the log (inclusion
+/// proof) and the checkpoint (signed root) to check that proof against.
+///
+/// This is the same trust layer [`crate::bundle::BundleInclusion`] attaches
+/// to a [`crate::bundle::MakotoBundle`], as a standalone value for callers
+/// that want to keep an attestation and its log position together without
+/// the rest of a bundle (keyless cert chain, media type envelope, ...).
+/// Check it with [`crate::verification::verify_inclusion`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransparencyLogEntry {
+    /// The signed attestation this entry attests was logged.
+    pub signed: SignedAttestation,
+
+    /// Where in the log this attestation sits, and its audit path.
+    pub proof: InclusionProof,
+
+    /// The log checkpoint `proof` is checked against.
+    pub checkpoint: LogCheckpoint,
+}
+
+impl TransparencyLogEntry {
+    /// Bind a signed attestation to its logged position and checkpoint.
+    pub fn new(signed: SignedAttestation, proof: InclusionProof, checkpoint: LogCheckpoint) -> Self {
+        Self {
+            signed,
+            proof,
+            checkpoint,
+        }
+    }
 }
 
 /// A signer for creating attestation signatures.
@@ -103,6 +563,8 @@ pub struct AttestationSignature {
 pub struct MakotoSigner {
     signing_key: SigningKey,
     key_id: String,
+    valid_from: Option<DateTime<Utc>>,
+    expires: Option<DateTime<Utc>>,
 }
 
 impl MakotoSigner {
@@ -114,6 +576,8 @@ impl MakotoSigner {
         Self {
             signing_key,
             key_id,
+            valid_from: None,
+            expires: None,
         }
     }
 
@@ -130,11 +594,41 @@ impl MakotoSigner {
         Ok(Self {
             signing_key,
             key_id,
+            valid_from: None,
+            expires: None,
         })
     }
 
-    /// Create a signer from raw key bytes.
+    /// Parse bytes produced by [`Self::to_bytes`]: a 1-byte
+    /// [`SignatureAlgorithm`] tag followed by that algorithm's raw key
+    /// material. Errors with [`MakotoError::UnsupportedAlgorithm`] if the
+    /// tag names a recognized-but-unbacked algorithm (Ed25519, RSA — see
+    /// [`SignatureAlgorithm`]), and with [`MakotoError::KeyError`] for an
+    /// unrecognized tag or malformed key material.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (tag, key_material) = bytes
+            .split_first()
+            .ok_or_else(|| MakotoError::KeyError("key bytes are empty".to_string()))?;
+        SignatureAlgorithm::from_tag(*tag)?.require_ecdsa_p256()?;
+
+        let signing_key = SigningKey::from_slice(key_material)
+            .map_err(|e| MakotoError::KeyError(format!("Invalid private key bytes: {}", e)))?;
+
+        let key_id = compute_key_id(signing_key.verifying_key());
+
+        Ok(Self {
+            signing_key,
+            key_id,
+            valid_from: None,
+            expires: None,
+        })
+    }
+
+    /// Build a signer directly from a raw P-256 scalar, with no algorithm
+    /// tag — for internal callers (e.g. [`crate::keysplit`]) doing scalar
+    /// arithmetic on the key material itself rather than round-tripping
+    /// through [`Self::to_bytes`]'s tagged wire format.
+    pub(crate) fn from_ecdsa_scalar_bytes(bytes: &[u8; 32]) -> Result<Self> {
         let signing_key = SigningKey::from_slice(bytes)
             .map_err(|e| MakotoError::KeyError(format!("Invalid private key bytes: {}", e)))?;
 
@@ -143,19 +637,58 @@ impl MakotoSigner {
         Ok(Self {
             signing_key,
             key_id,
+            valid_from: None,
+            expires: None,
         })
     }
 
+    /// Generate a new signer for `algorithm`. Only
+    /// [`SignatureAlgorithm::EcdsaP256`] is backed; `Ed25519` and
+    /// `RsaPkcs1Sha256` return [`MakotoError::UnsupportedAlgorithm`] rather
+    /// than silently falling back to P-256 — see [`SignatureAlgorithm`] for
+    /// why this crate doesn't hand-roll either.
+    pub fn generate_with(algorithm: SignatureAlgorithm) -> Result<Self> {
+        algorithm.require_ecdsa_p256()?;
+        Ok(Self::generate())
+    }
+
+    /// Set when this key becomes valid, carried onto [`Self::verifying_key`]'s
+    /// [`MakotoVerifier::not_before`] — signatures checked against a
+    /// reference time earlier than this are rejected with
+    /// [`MakotoError::KeyExpired`].
+    pub fn with_valid_from(mut self, valid_from: DateTime<Utc>) -> Self {
+        self.valid_from = Some(valid_from);
+        self
+    }
+
+    /// Set when this key retires, carried onto [`Self::verifying_key`]'s
+    /// [`MakotoVerifier::not_after`] — signatures checked against a
+    /// reference time at or after this are rejected with
+    /// [`MakotoError::KeyExpired`].
+    pub fn with_expires(mut self, expires: DateTime<Utc>) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
     /// Get the key ID.
     pub fn key_id(&self) -> &str {
         &self.key_id
     }
 
-    /// Get the verifying (public) key.
+    /// The algorithm this signer produces signatures with. Always
+    /// [`SignatureAlgorithm::EcdsaP256`] — see [`SignatureAlgorithm`] for why.
+    pub fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::EcdsaP256
+    }
+
+    /// Get the verifying (public) key, carrying over this signer's validity
+    /// window (if any) set via [`Self::with_valid_from`]/[`Self::with_expires`].
     pub fn verifying_key(&self) -> MakotoVerifier {
         MakotoVerifier {
             verifying_key: *self.signing_key.verifying_key(),
             key_id: self.key_id.clone(),
+            not_before: self.valid_from,
+            not_after: self.expires,
         }
     }
 
@@ -164,9 +697,25 @@ impl MakotoSigner {
         Ok(self.signing_key.sign(data))
     }
 
-    /// Export the private key as bytes.
+    /// Export the private key as an algorithm-tagged byte string: a 1-byte
+    /// [`SignatureAlgorithm`] tag (always [`SignatureAlgorithm::EcdsaP256`]
+    /// today — see [`SignatureAlgorithm`] for why) followed by the raw
+    /// 32-byte scalar, so [`Self::from_bytes`] can recover which algorithm
+    /// the key material is for instead of assuming P-256.
     pub fn to_bytes(&self) -> Vec<u8> {
-        self.signing_key.to_bytes().to_vec()
+        let mut out = Vec::with_capacity(33);
+        out.push(SignatureAlgorithm::EcdsaP256.to_tag());
+        out.extend_from_slice(&self.signing_key.to_bytes());
+        out
+    }
+
+    /// Raw 32-byte P-256 scalar for this signer's private key, with no
+    /// algorithm tag — for internal callers (e.g. [`crate::keysplit`]) that
+    /// need to do scalar arithmetic directly rather than round-trip through
+    /// [`Self::to_bytes`]'s tagged wire format.
+    pub(crate) fn ecdsa_scalar_bytes(&self) -> [u8; 32] {
+        let bytes = self.signing_key.to_bytes();
+        <[u8; 32]>::try_from(bytes.as_slice()).expect("P-256 scalar is always 32 bytes")
     }
 
     /// Export the public key as bytes.
@@ -184,6 +733,8 @@ impl MakotoSigner {
 pub struct MakotoVerifier {
     verifying_key: VerifyingKey,
     key_id: String,
+    not_before: Option<DateTime<Utc>>,
+    not_after: Option<DateTime<Utc>>,
 }
 
 impl MakotoVerifier {
@@ -205,14 +756,72 @@ impl MakotoVerifier {
         Ok(Self {
             verifying_key,
             key_id,
+            not_before: None,
+            not_after: None,
         })
     }
 
+    /// Set when this key becomes valid. A signature checked against a
+    /// reference time earlier than this is rejected with
+    /// [`MakotoError::KeyExpired`] rather than verified.
+    pub fn with_not_before(mut self, not_before: DateTime<Utc>) -> Self {
+        self.not_before = Some(not_before);
+        self
+    }
+
+    /// Set when this key retires. A signature checked against a reference
+    /// time at or after this is rejected with [`MakotoError::KeyExpired`]
+    /// rather than verified, even if the signature itself is mathematically
+    /// valid.
+    pub fn with_not_after(mut self, not_after: DateTime<Utc>) -> Self {
+        self.not_after = Some(not_after);
+        self
+    }
+
     /// Get the key ID.
     pub fn key_id(&self) -> &str {
         &self.key_id
     }
 
+    /// The algorithm this verifier checks signatures against. Always
+    /// [`SignatureAlgorithm::EcdsaP256`] — see [`SignatureAlgorithm`] for why.
+    pub fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::EcdsaP256
+    }
+
+    /// Check `reference_time` falls within this key's validity window (if
+    /// any was set via [`Self::with_not_before`]/[`Self::with_not_after`]),
+    /// failing closed with [`MakotoError::KeyExpired`] otherwise. A key with
+    /// no validity window set is always valid, matching pre-rotation-policy
+    /// behavior.
+    pub fn check_validity_at(&self, reference_time: DateTime<Utc>) -> Result<()> {
+        if let Some(not_before) = self.not_before {
+            if reference_time < not_before {
+                return Err(MakotoError::KeyExpired {
+                    key_id: self.key_id.clone(),
+                    reason: format!(
+                        "key is not valid until {} (checked at {})",
+                        not_before, reference_time
+                    ),
+                });
+            }
+        }
+
+        if let Some(not_after) = self.not_after {
+            if reference_time >= not_after {
+                return Err(MakotoError::KeyExpired {
+                    key_id: self.key_id.clone(),
+                    reason: format!(
+                        "key expired at {} (checked at {})",
+                        not_after, reference_time
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Verify a signature.
     pub fn verify(&self, data: &[u8], signature: &Signature) -> Result<bool> {
         match self.verifying_key.verify(data, signature) {
@@ -230,6 +839,211 @@ impl MakotoVerifier {
     }
 }
 
+/// One entry in a [`VerifierSet`]: a key plus the algorithm it's allowed to
+/// be used with.
+#[derive(Debug, Clone)]
+pub struct VerifierEntry {
+    /// The key itself.
+    pub verifier: MakotoVerifier,
+    /// Algorithm this key is allowed to sign/verify with (e.g.
+    /// [`SIGNATURE_ALGORITHM`]). An envelope declaring a different algorithm
+    /// for this key id is rejected even if the signature happens to check out.
+    pub algorithm: String,
+}
+
+/// A set of verifiers keyed by `key_id`, for deployments that rotate keys
+/// or mix algorithms rather than pin a single [`MakotoVerifier`].
+///
+/// This SDK only ever produces [`SIGNATURE_ALGORITHM`] (ECDSA P-256)
+/// signatures, so every [`VerifierEntry`] added today will in practice carry
+/// that same algorithm label — but [`verify_signed_attestation_multi`]
+/// checks the label rather than assuming it, so a deployment that mixes in
+/// verifiers for other algorithms (Ed25519, RSA, ...) behind the same
+/// lookup is rejected cleanly instead of silently mis-verifying.
+///
+/// [`verify_signed_attestation_multi`]: crate::verification::verify_signed_attestation_multi
+#[derive(Debug, Clone, Default)]
+pub struct VerifierSet {
+    entries: std::collections::HashMap<String, VerifierEntry>,
+}
+
+impl VerifierSet {
+    /// An empty set; add keys with [`Self::with_verifier`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a verifier under its own `key_id`, allowed to be used with `algorithm`.
+    pub fn with_verifier(mut self, verifier: MakotoVerifier, algorithm: impl Into<String>) -> Self {
+        let key_id = verifier.key_id().to_string();
+        self.entries.insert(
+            key_id,
+            VerifierEntry {
+                verifier,
+                algorithm: algorithm.into(),
+            },
+        );
+        self
+    }
+
+    /// Look up the entry for a `key_id`, if one was added.
+    pub fn get(&self, key_id: &str) -> Option<&VerifierEntry> {
+        self.entries.get(key_id)
+    }
+
+    /// Number of keys in the set.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the set has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Load a set from a JWKS-style JSON document so operators can publish
+    /// rotating public keys at a well-known URL:
+    ///
+    /// ```json
+    /// {"keys": [{"kid": "...", "alg": "ES256", "key": "<base64 public key bytes>"}]}
+    /// ```
+    ///
+    /// This SDK has no X.509/JWK parser (see [`crate::keyless`] for the same
+    /// tradeoff made for certificates), so unlike a real RFC 7517 JWK this
+    /// doesn't decode `kty`/`crv`/`x`/`y` EC point fields — `key` is this
+    /// SDK's own SEC1-encoded public key bytes, base64'd, kept alongside
+    /// `kid`/`alg` so the document stays JWKS-shaped for operators who
+    /// already have that publishing workflow.
+    pub fn from_jwks_json(json: &str) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Jwks {
+            keys: Vec<Jwk>,
+        }
+
+        #[derive(Deserialize)]
+        struct Jwk {
+            kid: String,
+            alg: String,
+            key: String,
+        }
+
+        let jwks: Jwks = serde_json::from_str(json)?;
+        let mut set = Self::new();
+
+        for jwk in jwks.keys {
+            let key_bytes = BASE64.decode(&jwk.key).map_err(|e| {
+                MakotoError::KeyError(format!("invalid JWKS key bytes for kid '{}': {}", jwk.kid, e))
+            })?;
+            let verifier = MakotoVerifier::from_bytes(&key_bytes)?;
+
+            if verifier.key_id() != jwk.kid {
+                return Err(MakotoError::KeyError(format!(
+                    "JWKS kid '{}' does not match its key's derived key id '{}'",
+                    jwk.kid,
+                    verifier.key_id()
+                )));
+            }
+
+            set = set.with_verifier(verifier, jwk.alg);
+        }
+
+        Ok(set)
+    }
+}
+
+/// The `key_id` of the key in a [`MakotoKeyring`] whose signature validated,
+/// returned by [`SignedAttestation::verify_with_keyring`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedKeyId(pub String);
+
+/// A set of trusted verifying keys, keyed by `key_id`, for relying parties
+/// that want to pin a whole org's key set rather than one key at a time —
+/// e.g. verifying attestations produced by a rotating fleet of collectors
+/// without already knowing which collector signed a given envelope.
+///
+/// Unlike [`VerifierSet`], a keyring carries no per-key algorithm label and
+/// is mutated in place (`add`/`remove`) rather than built with chained
+/// `with_*` calls, mirroring how a relying party actually maintains a trust
+/// store over time (keys get added and retired, one at a time). Reach for
+/// [`VerifierSet`] instead when mixed algorithms need to be distinguished
+/// and rejected.
+#[derive(Debug, Clone, Default)]
+pub struct MakotoKeyring {
+    keys: std::collections::HashMap<String, MakotoVerifier>,
+}
+
+impl MakotoKeyring {
+    /// An empty keyring; add keys with [`Self::add`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add (or replace) a key under its own `key_id`.
+    pub fn add(&mut self, verifier: MakotoVerifier) {
+        self.keys.insert(verifier.key_id().to_string(), verifier);
+    }
+
+    /// Remove a key by `key_id`, returning it if present.
+    pub fn remove(&mut self, key_id: &str) -> Option<MakotoVerifier> {
+        self.keys.remove(key_id)
+    }
+
+    /// Look up a key by `key_id`.
+    pub fn get(&self, key_id: &str) -> Option<&MakotoVerifier> {
+        self.keys.get(key_id)
+    }
+
+    /// Number of keys in the keyring.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Whether the keyring has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Load a keyring from a bundle of concatenated PEM public keys (as
+    /// produced by `cat *.pem`), one `-----BEGIN ... KEY-----`/`-----END
+    /// ... KEY-----` block per key.
+    pub fn from_pem_bundle(bundle: &str) -> Result<Self> {
+        let mut keyring = Self::new();
+
+        for block in split_pem_bundle(bundle) {
+            keyring.add(MakotoVerifier::from_pem(&block)?);
+        }
+
+        Ok(keyring)
+    }
+}
+
+/// Split a bundle of concatenated PEM blocks into its individual
+/// `-----BEGIN-----`..`-----END-----` blocks.
+fn split_pem_bundle(bundle: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current = String::new();
+    let mut in_block = false;
+
+    for line in bundle.lines() {
+        if line.starts_with("-----BEGIN") {
+            in_block = true;
+            current.clear();
+        }
+
+        if in_block {
+            current.push_str(line);
+            current.push('\n');
+        }
+
+        if line.starts_with("-----END") {
+            in_block = false;
+            blocks.push(std::mem::take(&mut current));
+        }
+    }
+
+    blocks
+}
+
 /// Compute a key ID from a verifying key (SHA-256 of the public key bytes).
 fn compute_key_id(key: &VerifyingKey) -> String {
     let bytes = key.to_encoded_point(false);
@@ -312,6 +1126,209 @@ mod tests {
         assert_eq!(decoded.predicate.origin.source, attestation.predicate.origin.source);
     }
 
+    #[test]
+    fn test_pre_auth_encoding_matches_dsse_spec() {
+        let pae = pre_auth_encoding("application/vnd.in-toto+json", b"hello");
+        assert_eq!(
+            pae,
+            b"DSSEv1 28 application/vnd.in-toto+json 5 hello".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_payload() {
+        let signer = MakotoSigner::generate();
+
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let collector = Collector::new("https://example.com/collector/001");
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(collector)
+            .build()
+            .unwrap();
+
+        let mut signed = SignedAttestation::sign(&attestation, &signer).unwrap();
+        // Tamper with the base64 payload without re-signing; since the PAE binds
+        // the raw payload bytes, this must invalidate the signature.
+        signed.payload = BASE64.encode(b"{\"tampered\":true}");
+
+        let verifier = signer.verifying_key();
+        assert!(!signed.verify(&verifier).unwrap());
+    }
+
+    #[test]
+    fn test_add_signature_co_signs_same_payload() {
+        let collector = MakotoSigner::generate();
+        let notary = MakotoSigner::generate();
+
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap();
+
+        let mut signed = SignedAttestation::sign(&attestation, &collector).unwrap();
+        signed.add_signature(&notary).unwrap();
+
+        assert_eq!(signed.signatures.len(), 2);
+        assert!(signed.verify(&collector.verifying_key()).unwrap());
+        assert!(signed.verify(&notary.verifying_key()).unwrap());
+    }
+
+    fn fake_pem(verifier: &MakotoVerifier) -> String {
+        format!(
+            "-----BEGIN PUBLIC KEY-----\n{}\n-----END PUBLIC KEY-----\n",
+            BASE64.encode(verifier.to_bytes())
+        )
+    }
+
+    #[test]
+    fn test_keyring_add_remove() {
+        let signer = MakotoSigner::generate();
+        let mut keyring = MakotoKeyring::new();
+        assert!(keyring.is_empty());
+
+        keyring.add(signer.verifying_key());
+        assert_eq!(keyring.len(), 1);
+        assert!(keyring.get(signer.key_id()).is_some());
+
+        let removed = keyring.remove(signer.key_id());
+        assert!(removed.is_some());
+        assert!(keyring.is_empty());
+    }
+
+    #[test]
+    fn test_keyring_from_pem_bundle_loads_multiple_keys() {
+        let a = MakotoSigner::generate();
+        let b = MakotoSigner::generate();
+        let bundle = format!(
+            "{}{}",
+            fake_pem(&a.verifying_key()),
+            fake_pem(&b.verifying_key())
+        );
+
+        let keyring = MakotoKeyring::from_pem_bundle(&bundle).unwrap();
+        assert_eq!(keyring.len(), 2);
+        assert!(keyring.get(a.key_id()).is_some());
+        assert!(keyring.get(b.key_id()).is_some());
+    }
+
+    #[test]
+    fn test_verify_with_keyring_finds_matching_key() {
+        let signer = MakotoSigner::generate();
+        let other = MakotoSigner::generate();
+
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap();
+
+        let signed = SignedAttestation::sign(&attestation, &signer).unwrap();
+
+        let mut keyring = MakotoKeyring::new();
+        keyring.add(other.verifying_key());
+        keyring.add(signer.verifying_key());
+
+        let result = signed.verify_with_keyring(&keyring).unwrap();
+        assert_eq!(result, Some(VerifiedKeyId(signer.key_id().to_string())));
+    }
+
+    #[test]
+    fn test_verify_with_keyring_returns_none_for_unknown_signer() {
+        let signer = MakotoSigner::generate();
+        let other = MakotoSigner::generate();
+
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap();
+
+        let signed = SignedAttestation::sign(&attestation, &signer).unwrap();
+
+        let mut keyring = MakotoKeyring::new();
+        keyring.add(other.verifying_key());
+
+        assert_eq!(signed.verify_with_keyring(&keyring).unwrap(), None);
+    }
+
+    #[test]
+    fn test_signature_algorithm_label_round_trip() {
+        assert_eq!(SignatureAlgorithm::EcdsaP256.label(), SIGNATURE_ALGORITHM);
+        assert_eq!(
+            SignatureAlgorithm::from_label(SIGNATURE_ALGORITHM).unwrap(),
+            SignatureAlgorithm::EcdsaP256
+        );
+        assert_eq!(
+            SignatureAlgorithm::from_label("EdDSA").unwrap(),
+            SignatureAlgorithm::Ed25519
+        );
+        assert!(SignatureAlgorithm::from_label("bogus").is_err());
+    }
+
+    #[test]
+    fn test_generate_with_supports_only_ecdsa_p256() {
+        assert!(MakotoSigner::generate_with(SignatureAlgorithm::EcdsaP256).is_ok());
+
+        let err = MakotoSigner::generate_with(SignatureAlgorithm::Ed25519).unwrap_err();
+        assert!(matches!(err, MakotoError::UnsupportedAlgorithm(_)));
+
+        let err = MakotoSigner::generate_with(SignatureAlgorithm::RsaPkcs1Sha256).unwrap_err();
+        assert!(matches!(err, MakotoError::UnsupportedAlgorithm(_)));
+    }
+
+    #[test]
+    fn test_verify_rejects_unsupported_declared_algorithm() {
+        let signer = MakotoSigner::generate();
+
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap();
+
+        let mut signed = SignedAttestation::sign(&attestation, &signer).unwrap();
+        signed.signatures[0].algorithm = Some("EdDSA".to_string());
+
+        let result = signed.verify(&signer.verifying_key());
+        assert!(matches!(result, Err(MakotoError::UnsupportedAlgorithm(_))));
+    }
+
     #[test]
     fn test_signer_roundtrip() {
         let signer = MakotoSigner::generate();
@@ -321,6 +1338,26 @@ mod tests {
         assert_eq!(signer.key_id(), restored.key_id());
     }
 
+    #[test]
+    fn test_from_bytes_rejects_non_ecdsa_tag() {
+        let signer = MakotoSigner::generate();
+        let mut bytes = signer.to_bytes();
+        bytes[0] = SignatureAlgorithm::Ed25519.to_tag();
+
+        let err = MakotoSigner::from_bytes(&bytes).unwrap_err();
+        assert!(matches!(err, MakotoError::UnsupportedAlgorithm(_)));
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_unknown_tag() {
+        let signer = MakotoSigner::generate();
+        let mut bytes = signer.to_bytes();
+        bytes[0] = 0xFF;
+
+        let err = MakotoSigner::from_bytes(&bytes).unwrap_err();
+        assert!(matches!(err, MakotoError::KeyError(_)));
+    }
+
     #[test]
     fn test_invalid_signature_fails() {
         let signer1 = MakotoSigner::generate();
@@ -333,4 +1370,210 @@ mod tests {
         let verifier2 = signer2.verifying_key();
         assert!(!verifier2.verify(data, &signature).unwrap());
     }
+
+    #[test]
+    fn test_with_claims_attaches_claims() {
+        let signer = MakotoSigner::generate();
+
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let collector = Collector::new("https://example.com/collector/001");
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(collector)
+            .build()
+            .unwrap();
+
+        let signed = SignedAttestation::sign(&attestation, &signer)
+            .unwrap()
+            .with_claims(AttestationClaims::new("collector-001").with_audience("downstream"));
+
+        assert_eq!(signed.claims.as_ref().unwrap().issuer, "collector-001");
+        assert_eq!(
+            signed.claims.as_ref().unwrap().audience.as_deref(),
+            Some("downstream")
+        );
+    }
+
+    #[test]
+    fn test_log_checkpoint_sign_and_verify() {
+        let signer = MakotoSigner::generate();
+        let checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 8, "a".repeat(64), &signer).unwrap();
+
+        let verifier = signer.verifying_key();
+        assert!(checkpoint.verify_signature(&verifier).unwrap());
+    }
+
+    #[test]
+    fn test_log_checkpoint_rejects_wrong_key() {
+        let signer = MakotoSigner::generate();
+        let other = MakotoSigner::generate();
+        let checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 8, "a".repeat(64), &signer).unwrap();
+
+        assert!(!checkpoint.verify_signature(&other.verifying_key()).unwrap());
+    }
+
+    #[test]
+    fn test_log_checkpoint_rejects_tampered_root() {
+        let signer = MakotoSigner::generate();
+        let mut checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 8, "a".repeat(64), &signer).unwrap();
+        checkpoint.root_hash = "b".repeat(64);
+
+        assert!(!checkpoint.verify_signature(&signer.verifying_key()).unwrap());
+    }
+
+    #[test]
+    fn test_verifier_set_get_by_key_id() {
+        let signer = MakotoSigner::generate();
+        let verifier = signer.verifying_key();
+        let key_id = verifier.key_id().to_string();
+
+        let set = VerifierSet::new().with_verifier(verifier, SIGNATURE_ALGORITHM);
+
+        assert_eq!(set.len(), 1);
+        let entry = set.get(&key_id).unwrap();
+        assert_eq!(entry.algorithm, SIGNATURE_ALGORITHM);
+    }
+
+    #[test]
+    fn test_verifier_set_from_jwks_json_round_trip() {
+        let signer = MakotoSigner::generate();
+        let verifier = signer.verifying_key();
+        let key_id = verifier.key_id().to_string();
+        let key_b64 = BASE64.encode(verifier.to_bytes());
+
+        let jwks = format!(
+            r#"{{"keys": [{{"kid": "{key_id}", "alg": "{SIGNATURE_ALGORITHM}", "key": "{key_b64}"}}]}}"#
+        );
+
+        let set = VerifierSet::from_jwks_json(&jwks).unwrap();
+        let entry = set.get(&key_id).unwrap();
+        assert_eq!(entry.algorithm, SIGNATURE_ALGORITHM);
+        assert_eq!(entry.verifier.key_id(), key_id);
+    }
+
+    #[test]
+    fn test_verifier_set_from_jwks_json_rejects_mismatched_kid() {
+        let signer = MakotoSigner::generate();
+        let verifier = signer.verifying_key();
+        let key_b64 = BASE64.encode(verifier.to_bytes());
+
+        let jwks = format!(
+            r#"{{"keys": [{{"kid": "not-the-real-kid", "alg": "{SIGNATURE_ALGORITHM}", "key": "{key_b64}"}}]}}"#
+        );
+
+        assert!(VerifierSet::from_jwks_json(&jwks).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_signature_from_not_yet_valid_key() {
+        use chrono::Duration;
+
+        let signer = MakotoSigner::generate();
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(Origin::new(
+                "https://api.example.com/data",
+                SourceType::Api,
+                CollectionMethod::Pull,
+                Utc::now(),
+            ))
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap();
+        let signed = SignedAttestation::sign(&attestation, &signer).unwrap();
+
+        let verifier = signer
+            .verifying_key()
+            .with_not_before(Utc::now() + Duration::days(1));
+
+        let result = signed.verify(&verifier);
+        assert!(matches!(result, Err(MakotoError::KeyExpired { .. })));
+    }
+
+    #[test]
+    fn test_verify_rejects_signature_from_expired_key() {
+        use chrono::Duration;
+
+        let signer = MakotoSigner::generate();
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(Origin::new(
+                "https://api.example.com/data",
+                SourceType::Api,
+                CollectionMethod::Pull,
+                Utc::now(),
+            ))
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap();
+        let signed = SignedAttestation::sign(&attestation, &signer).unwrap();
+
+        let verifier = signer
+            .verifying_key()
+            .with_not_after(Utc::now() - Duration::days(1));
+
+        let result = signed.verify(&verifier);
+        assert!(matches!(result, Err(MakotoError::KeyExpired { .. })));
+    }
+
+    #[test]
+    fn test_verify_at_accepts_key_within_validity_window() {
+        use chrono::Duration;
+
+        let signer = MakotoSigner::generate()
+            .with_valid_from(Utc::now() - Duration::days(1))
+            .with_expires(Utc::now() + Duration::days(1));
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(Origin::new(
+                "https://api.example.com/data",
+                SourceType::Api,
+                CollectionMethod::Pull,
+                Utc::now(),
+            ))
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap();
+        let signed = SignedAttestation::sign(&attestation, &signer).unwrap();
+
+        assert!(signed.verify(&signer.verifying_key()).unwrap());
+    }
+
+    #[test]
+    fn test_verify_with_keyring_rejects_expired_key() {
+        use chrono::Duration;
+
+        let signer = MakotoSigner::generate();
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(Origin::new(
+                "https://api.example.com/data",
+                SourceType::Api,
+                CollectionMethod::Pull,
+                Utc::now(),
+            ))
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap();
+        let signed = SignedAttestation::sign(&attestation, &signer).unwrap();
+
+        let mut keyring = MakotoKeyring::new();
+        keyring.add(
+            signer
+                .verifying_key()
+                .with_not_after(Utc::now() - Duration::days(1)),
+        );
+
+        let result = signed.verify_with_keyring(&keyring);
+        assert!(matches!(result, Err(MakotoError::KeyExpired { .. })));
+    }
 }
@@ -0,0 +1,295 @@
+//! Sparse Merkle tree keyed by a 256-bit identifier, for proving that a
+//! key is *absent* from an attestation set ("this artifact was never
+//! attested"), not just what's present.
+//!
+//! Unlike [`crate::hash::MerkleTree`], which is append-ordered over record
+//! position, a [`SparseMerkleTree`] is keyed by a fixed 256-bit identifier
+//! (e.g. `sha256_hex` of a record key) at a fixed depth of 256 — every
+//! possible key has a canonical, empty-by-default slot, so a proof can
+//! show a slot is provably empty.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use sha2::{Digest, Sha256};
+
+/// Number of levels in the tree; one per bit of the 256-bit key.
+const DEPTH: usize = 256;
+
+/// `zero_hashes()[i]` is the root of an empty subtree of height `i`.
+/// `zero_hashes()[0]` is the empty-leaf hash; each level up duplicates the
+/// one below it, so an all-empty subtree of any size collapses to one of
+/// these 257 cached constants instead of being materialized. This is what
+/// keeps the tree `O(inserts)` in memory despite its depth-256 shape.
+fn zero_hashes() -> &'static [[u8; 32]; DEPTH + 1] {
+    static ZERO_HASHES: OnceLock<[[u8; 32]; DEPTH + 1]> = OnceLock::new();
+    ZERO_HASHES.get_or_init(|| {
+        let mut hashes = [[0u8; 32]; DEPTH + 1];
+        hashes[0] = empty_leaf_hash();
+        for i in 1..=DEPTH {
+            hashes[i] = hash_pair(&hashes[i - 1], &hashes[i - 1]);
+        }
+        hashes
+    })
+}
+
+fn empty_leaf_hash() -> [u8; 32] {
+    digest(b"makoto-sparse-merkle-tree:empty-leaf")
+}
+
+fn hash_leaf(value: &[u8]) -> [u8; 32] {
+    digest(value)
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    let mut arr = [0u8; 32];
+    arr.copy_from_slice(&hasher.finalize());
+    arr
+}
+
+fn digest(data: &[u8]) -> [u8; 32] {
+    let mut arr = [0u8; 32];
+    arr.copy_from_slice(&Sha256::digest(data));
+    arr
+}
+
+/// Whether bit `depth` (0 = most significant) of `key` is set.
+fn bit(key: &[u8; 32], depth: usize) -> bool {
+    let byte = key[depth / 8];
+    let shift = 7 - (depth % 8);
+    (byte >> shift) & 1 == 1
+}
+
+/// A sparse Merkle tree over 256-bit keys, supporting both inclusion and
+/// non-inclusion proofs.
+///
+/// Memory is `O(inserts)`: only inserted leaves are stored in `leaves`, and
+/// any subtree with no inserted keys collapses to a [`zero_hashes`]
+/// constant rather than being materialized. `root()`/`proof()` walk the
+/// full key set, so they're `O(inserts * 256)`, not `O(1)`.
+#[derive(Debug, Clone, Default)]
+pub struct SparseMerkleTree {
+    leaves: HashMap<[u8; 32], [u8; 32]>,
+}
+
+impl SparseMerkleTree {
+    /// Create an empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert (or overwrite) the value at `key`.
+    pub fn insert(&mut self, key: [u8; 32], value: &[u8]) {
+        self.leaves.insert(key, hash_leaf(value));
+    }
+
+    /// Remove `key`, returning its slot to the provably-empty state.
+    pub fn remove(&mut self, key: &[u8; 32]) {
+        self.leaves.remove(key);
+    }
+
+    /// Number of inserted keys.
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Whether the tree has no inserted keys.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Root hash of the tree.
+    pub fn root(&self) -> [u8; 32] {
+        let keys: Vec<[u8; 32]> = self.leaves.keys().copied().collect();
+        self.node_hash(0, &keys)
+    }
+
+    /// Generate a proof for `key`: 256 sibling hashes, ordered from the
+    /// root's children down to the leaf.
+    ///
+    /// If `key` was never inserted, this is a non-inclusion proof — its
+    /// `leaf_hash` is `None`, and verifying it against the tree's root
+    /// demonstrates the key was never attested.
+    pub fn proof(&self, key: [u8; 32]) -> SparseMerkleProof {
+        let keys: Vec<[u8; 32]> = self.leaves.keys().copied().collect();
+        let mut siblings = Vec::with_capacity(DEPTH);
+        self.collect_siblings(0, &keys, &key, &mut siblings);
+
+        SparseMerkleProof {
+            key,
+            leaf_hash: self.leaves.get(&key).copied(),
+            siblings,
+        }
+    }
+
+    /// Hash of the subtree at `depth` containing exactly `keys` (all of
+    /// which share the path taken to reach `depth`).
+    fn node_hash(&self, depth: usize, keys: &[[u8; 32]]) -> [u8; 32] {
+        if keys.is_empty() {
+            return zero_hashes()[DEPTH - depth];
+        }
+        if depth == DEPTH {
+            return self.leaves[&keys[0]];
+        }
+
+        let (left, right): (Vec<[u8; 32]>, Vec<[u8; 32]>) =
+            keys.iter().copied().partition(|k| !bit(k, depth));
+        let left_hash = self.node_hash(depth + 1, &left);
+        let right_hash = self.node_hash(depth + 1, &right);
+        hash_pair(&left_hash, &right_hash)
+    }
+
+    /// Walk the path to `target`, pushing the sibling hash at each level.
+    fn collect_siblings(
+        &self,
+        depth: usize,
+        keys: &[[u8; 32]],
+        target: &[u8; 32],
+        siblings: &mut Vec<[u8; 32]>,
+    ) {
+        if depth == DEPTH {
+            return;
+        }
+
+        let (left, right): (Vec<[u8; 32]>, Vec<[u8; 32]>) =
+            keys.iter().copied().partition(|k| !bit(k, depth));
+
+        if bit(target, depth) {
+            siblings.push(self.node_hash(depth + 1, &left));
+            self.collect_siblings(depth + 1, &right, target, siblings);
+        } else {
+            siblings.push(self.node_hash(depth + 1, &right));
+            self.collect_siblings(depth + 1, &left, target, siblings);
+        }
+    }
+}
+
+/// Inclusion or non-inclusion proof for one key of a [`SparseMerkleTree`].
+#[derive(Debug, Clone)]
+pub struct SparseMerkleProof {
+    /// Key the proof is for.
+    pub key: [u8; 32],
+    /// The leaf's value hash, or `None` if `key` was absent when the proof
+    /// was generated (a non-inclusion proof).
+    pub leaf_hash: Option<[u8; 32]>,
+    /// Sibling hashes from root to leaf, one per depth level (`siblings[d]`
+    /// is the sibling encountered walking through depth `d`, where depth 0
+    /// is just below the root).
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl SparseMerkleProof {
+    /// Recompute the root implied by this proof.
+    pub fn compute_root(&self) -> [u8; 32] {
+        let mut current = self.leaf_hash.unwrap_or_else(|| zero_hashes()[0]);
+
+        for depth in (0..DEPTH).rev() {
+            let sibling = &self.siblings[depth];
+            current = if bit(&self.key, depth) {
+                hash_pair(sibling, &current)
+            } else {
+                hash_pair(&current, sibling)
+            };
+        }
+
+        current
+    }
+
+    /// Verify this proof (inclusion if `leaf_hash` is `Some`, non-inclusion
+    /// if `None`) against an expected root.
+    pub fn verify(&self, expected_root: &[u8; 32]) -> bool {
+        self.siblings.len() == DEPTH && &self.compute_root() == expected_root
+    }
+
+    /// Whether this is a non-inclusion (absence) proof.
+    pub fn is_non_inclusion(&self) -> bool {
+        self.leaf_hash.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> [u8; 32] {
+        let mut k = [0u8; 32];
+        k[31] = byte;
+        k
+    }
+
+    #[test]
+    fn test_empty_tree_root_is_zero_hash() {
+        let tree = SparseMerkleTree::new();
+        assert_eq!(tree.root(), zero_hashes()[DEPTH]);
+    }
+
+    #[test]
+    fn test_insert_changes_root() {
+        let mut tree = SparseMerkleTree::new();
+        let empty_root = tree.root();
+        tree.insert(key(1), b"value");
+        assert_ne!(tree.root(), empty_root);
+    }
+
+    #[test]
+    fn test_inclusion_proof_verifies() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key(1), b"value-one");
+        tree.insert(key(2), b"value-two");
+
+        let root = tree.root();
+        let proof = tree.proof(key(1));
+        assert!(!proof.is_non_inclusion());
+        assert!(proof.verify(&root));
+    }
+
+    #[test]
+    fn test_non_inclusion_proof_verifies() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key(1), b"value-one");
+
+        let root = tree.root();
+        let proof = tree.proof(key(99));
+        assert!(proof.is_non_inclusion());
+        assert!(proof.verify(&root));
+    }
+
+    #[test]
+    fn test_non_inclusion_proof_fails_after_insert() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key(1), b"value-one");
+        let root_before = tree.root();
+        let absence_proof = tree.proof(key(99));
+        assert!(absence_proof.verify(&root_before));
+
+        tree.insert(key(99), b"value-ninety-nine");
+        let root_after = tree.root();
+        // The old absence proof must not verify against the new root.
+        assert!(!absence_proof.verify(&root_after));
+    }
+
+    #[test]
+    fn test_remove_reverts_to_non_inclusion() {
+        let mut tree = SparseMerkleTree::new();
+        tree.insert(key(1), b"value-one");
+        tree.remove(&key(1));
+
+        let root = tree.root();
+        assert_eq!(root, SparseMerkleTree::new().root());
+        let proof = tree.proof(key(1));
+        assert!(proof.is_non_inclusion());
+        assert!(proof.verify(&root));
+    }
+
+    #[test]
+    fn test_len_and_is_empty() {
+        let mut tree = SparseMerkleTree::new();
+        assert!(tree.is_empty());
+        tree.insert(key(1), b"value");
+        assert_eq!(tree.len(), 1);
+        assert!(!tree.is_empty());
+    }
+}
@@ -0,0 +1,259 @@
+//! Submitting [`SignedAttestation`]s to an append-only transparency log and
+//! carrying the resulting inclusion proof alongside the envelope.
+//!
+//! [`TransparencyLog`] models a log as two separate round trips —
+//! [`TransparencyLog::submit`] to record an entry, [`TransparencyLog::get_inclusion_proof`]
+//! to fetch (or re-fetch) its audit path once the log has checkpointed it —
+//! matching how a Certificate-Transparency-style log actually works: an
+//! entry is often accepted before it's merged into a checkpointed tree, so
+//! the proof isn't always available at submission time. This is a
+//! different shape from [`crate::bundle::TransparencyLogClient`], whose
+//! single `submit` call returns the proof immediately for the keyless
+//! signing flow in [`crate::bundle::keyless_sign`], where the log is
+//! expected to checkpoint synchronously.
+//!
+//! This module does not include a concrete HTTP client: this tree has no
+//! HTTP dependency (no `Cargo.toml`, no `reqwest`/`ureq`), and hand-rolling
+//! a raw TCP/TLS request path instead of using an audited HTTP stack would
+//! be worse than not shipping one. A deployment that needs a real log
+//! should implement [`TransparencyLog`] against whatever HTTP client it
+//! already depends on — the trait is the extension point, not a stub
+//! awaiting completion here. [`InMemoryTransparencyLog`] is a real,
+//! non-stub reference implementation — useful for tests and local
+//! development, and as the template such a client would follow — built on
+//! the same [`crate::hash::MerkleTree`]/[`HashMode::Rfc6962`]
+//! machinery [`crate::merkle`] already uses for stream windows. It only
+//! supports fetching an inclusion proof once the log holds a power-of-two
+//! number of entries: at other sizes [`crate::hash::MerkleTree::proof`]
+//! emits a placeholder entry for each carried-up odd node, which the RFC
+//! 6962 audit path folded by
+//! [`crate::verification::verify_transparency_inclusion`] does not contain.
+
+use std::sync::Mutex;
+
+use crate::error::{MakotoError, Result};
+use crate::hash::{HashMode, MerkleTree};
+use crate::types::HashAlgorithm;
+use crate::signing::{InclusionProof, LogCheckpoint, MakotoSigner, SignedAttestation};
+
+/// A transparency log's record of one submitted [`SignedAttestation`]: its
+/// position (once an inclusion proof is available) and the checkpoint that
+/// proof is checked against.
+///
+/// Lighter than [`crate::signing::TransparencyLogEntry`], which re-embeds
+/// the whole [`SignedAttestation`] — this is the shape
+/// [`SignedAttestation::log_entries`] carries instead, since embedding
+/// [`crate::signing::TransparencyLogEntry`] there would nest the envelope
+/// inside a copy of itself.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    /// The log's own identifier for this entry (e.g. a Rekor UUID, or this
+    /// reference log's leaf index as a string).
+    pub entry_id: String,
+
+    /// This entry's position and audit path, if the log has checkpointed
+    /// it yet (empty audit path otherwise).
+    pub proof: InclusionProof,
+
+    /// The checkpoint `proof` is checked against.
+    pub checkpoint: LogCheckpoint,
+}
+
+/// An append-only transparency log a [`SignedAttestation`] can be submitted
+/// to for public, non-repudiable recording.
+pub trait TransparencyLog {
+    /// Submit `signed` for inclusion, returning the resulting [`LogEntry`].
+    fn submit(&self, signed: &SignedAttestation) -> Result<LogEntry>;
+
+    /// Re-fetch the inclusion proof for a previously submitted entry.
+    fn get_inclusion_proof(&self, entry_id: &str) -> Result<InclusionProof>;
+}
+
+/// A [`TransparencyLog`] backed by an in-process [`MerkleTree`], signing
+/// its own checkpoints with a dedicated log key — no network, no
+/// persistence. See the module docs for why this (not an HTTP client) is
+/// what ships today.
+///
+/// Interior mutability ([`Mutex`]) rather than `&mut self` on
+/// [`TransparencyLog::submit`], since that trait is meant to also cover a
+/// future HTTP client, where the log's state lives on a remote server and
+/// a local `&self` call is already the right shape.
+pub struct InMemoryTransparencyLog {
+    origin: String,
+    signer: MakotoSigner,
+    leaves: Mutex<Vec<Vec<u8>>>,
+}
+
+impl InMemoryTransparencyLog {
+    /// Create an empty log identified by `origin` (e.g. a log URL),
+    /// signing checkpoints with `signer`.
+    pub fn new(origin: impl Into<String>, signer: MakotoSigner) -> Self {
+        Self {
+            origin: origin.into(),
+            signer,
+            leaves: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Number of entries currently in the log.
+    pub fn len(&self) -> usize {
+        self.leaves.lock().expect("log mutex poisoned").len()
+    }
+
+    /// Whether the log has no entries yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn tree_over(leaves: &[Vec<u8>]) -> Result<MerkleTree> {
+        let leaves: Vec<&[u8]> = leaves.iter().map(|l| l.as_slice()).collect();
+        MerkleTree::from_leaves_with_options(&leaves, HashAlgorithm::Sha256, HashMode::Rfc6962)
+    }
+
+    fn checkpoint_over(&self, leaves: &[Vec<u8>]) -> Result<LogCheckpoint> {
+        let root = Self::tree_over(leaves)?
+            .root_hex()
+            .ok_or_else(|| MakotoError::MerkleError("cannot checkpoint an empty log".to_string()))?;
+        LogCheckpoint::sign(self.origin.clone(), leaves.len() as u64, root, &self.signer)
+    }
+
+    /// The proof for `leaf_index` given a log holding exactly `leaves`
+    /// entries, or an error if `leaves.len()` isn't a power of two — see
+    /// the module docs' balanced-tree limitation.
+    fn proof_over(leaves: &[Vec<u8>], leaf_index: usize) -> Result<InclusionProof> {
+        if leaf_index >= leaves.len() {
+            return Err(MakotoError::MerkleError(format!(
+                "entry {leaf_index} out of range ({} entries logged)",
+                leaves.len()
+            )));
+        }
+
+        if !leaves.len().is_power_of_two() {
+            return Err(MakotoError::MerkleError(format!(
+                "log currently has {} entries; inclusion proofs are only available at a \
+                 power-of-two tree size (see module docs)",
+                leaves.len()
+            )));
+        }
+
+        let proof = Self::tree_over(leaves)?.proof(leaf_index)?;
+        let audit_path = proof.siblings.iter().map(hex::encode).collect();
+
+        Ok(InclusionProof::new(leaf_index as u64, leaves.len() as u64, audit_path))
+    }
+}
+
+impl TransparencyLog for InMemoryTransparencyLog {
+    fn submit(&self, signed: &SignedAttestation) -> Result<LogEntry> {
+        let leaf = crate::types::to_canonical_json(signed)?.into_bytes();
+
+        let mut leaves = self.leaves.lock().expect("log mutex poisoned");
+        leaves.push(leaf);
+        let leaf_index = leaves.len() - 1;
+
+        let checkpoint = self.checkpoint_over(&leaves)?;
+        let proof = Self::proof_over(&leaves, leaf_index)
+            .unwrap_or_else(|_| InclusionProof::new(leaf_index as u64, leaves.len() as u64, vec![]));
+
+        Ok(LogEntry {
+            entry_id: leaf_index.to_string(),
+            proof,
+            checkpoint,
+        })
+    }
+
+    fn get_inclusion_proof(&self, entry_id: &str) -> Result<InclusionProof> {
+        let leaf_index: usize = entry_id
+            .parse()
+            .map_err(|_| MakotoError::MerkleError(format!("unknown entry id: {entry_id}")))?;
+
+        let leaves = self.leaves.lock().expect("log mutex poisoned");
+        Self::proof_over(&leaves, leaf_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::MakotoSigner;
+    use crate::types::common::{CollectionMethod, SourceType};
+    use crate::types::origin::{Collector, Origin};
+    use crate::types::{Digest, OriginAttestation, Subject};
+    use crate::verification::verify_transparency_inclusion;
+    use chrono::Utc;
+
+    fn sample_signed(signer: &MakotoSigner) -> SignedAttestation {
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(Origin::new(
+                "https://api.example.com/data",
+                SourceType::Api,
+                CollectionMethod::Pull,
+                Utc::now(),
+            ))
+            .collector(Collector::new("https://example.com/collector/001"))
+            .build()
+            .unwrap();
+
+        SignedAttestation::sign(&attestation, signer).unwrap()
+    }
+
+    #[test]
+    fn test_submit_and_verify_inclusion_at_power_of_two_size() {
+        let attestation_signer = MakotoSigner::generate();
+        let log = InMemoryTransparencyLog::new("https://log.example.com", MakotoSigner::generate());
+
+        let mut signed_entries = Vec::new();
+        let mut checkpoint = None;
+        for _ in 0..4 {
+            let signed = sample_signed(&attestation_signer);
+            let entry = log.submit(&signed).unwrap();
+            checkpoint = Some(entry.checkpoint.clone());
+            signed_entries.push((signed, entry));
+        }
+
+        // At exactly 4 entries (power of two), the proof taken for the
+        // first entry verifies against the checkpoint from the last
+        // submission in this batch (same tree_size for both).
+        let (first_signed, first_entry) = &signed_entries[0];
+        let proof = log.get_inclusion_proof(&first_entry.entry_id).unwrap();
+        assert_eq!(proof.audit_path.len(), 2);
+
+        let leaf = crate::types::to_canonical_json(first_signed).unwrap();
+        let result = verify_transparency_inclusion(
+            leaf.as_bytes(),
+            &proof,
+            checkpoint.as_ref().unwrap(),
+            &log.signer.verifying_key(),
+        );
+        assert!(result.valid, "{:?}", result.messages);
+    }
+
+    #[test]
+    fn test_get_inclusion_proof_rejects_non_power_of_two_size() {
+        let attestation_signer = MakotoSigner::generate();
+        let log = InMemoryTransparencyLog::new("https://log.example.com", MakotoSigner::generate());
+
+        log.submit(&sample_signed(&attestation_signer)).unwrap();
+        log.submit(&sample_signed(&attestation_signer)).unwrap();
+        log.submit(&sample_signed(&attestation_signer)).unwrap();
+
+        assert!(log.get_inclusion_proof("0").is_err());
+    }
+
+    #[test]
+    fn test_signed_attestation_carries_log_entries() {
+        let signer = MakotoSigner::generate();
+        let log = InMemoryTransparencyLog::new("https://log.example.com", MakotoSigner::generate());
+
+        for _ in 0..3 {
+            log.submit(&sample_signed(&signer)).unwrap();
+        }
+        let signed = sample_signed(&signer);
+        let entry = log.submit(&signed).unwrap();
+        let signed = signed.with_log_entry(entry);
+
+        assert_eq!(signed.log_entries.as_ref().unwrap().len(), 1);
+    }
+}
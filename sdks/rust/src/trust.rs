@@ -0,0 +1,274 @@
+//! A TUF-style trust root mapping attested identities (the
+//! `https://expanso.io/...`-style URIs used in [`crate::types::origin::Collector`],
+//! [`crate::types::transform::Executor`], and
+//! [`crate::types::stream_window::CollectorDescriptor`]) to the `key_id`s
+//! currently authorized to sign on their behalf.
+//!
+//! Without this, a relying party calling [`SignedAttestation::verify`] has
+//! to already know the right public key out of band — there's no way to
+//! discover or roll over which keys are currently trusted for a given
+//! collector/executor. [`TrustManifest`] is that discovery document: a
+//! versioned, expiring mapping, signed by a threshold of root keys exactly
+//! like any other Makoto payload — reusing [`SignedAttestation`]/[`VerifierSet`]/
+//! [`crate::verification::verify_threshold`] rather than inventing a
+//! parallel signature format, since a trust manifest is just another
+//! `Serialize` payload as far as the DSSE envelope is concerned.
+//!
+//! [`TrustRoot::fetch`] does not make a network request: this tree has no
+//! HTTP dependency (no `Cargo.toml`, no `reqwest`/`ureq`), so it returns
+//! [`MakotoError::KeyError`] explaining that a caller should fetch the
+//! manifest bytes themselves (however their environment does HTTP) and
+//! hand them to [`TrustRoot::from_json`]. [`TrustRoot::update`] is the
+//! "cache it locally" half of the request: once a manifest has been
+//! verified, it lives in the `TrustRoot` value until a *newer* (monotonic
+//! `version`) one replaces it, rejecting any attempted rollback to an
+//! equal-or-older version.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{MakotoError, Result};
+use crate::signing::{SignedAttestation, VerifierSet};
+use crate::verification::verify_threshold;
+
+/// A versioned, expiring map from attested identity URI to the `key_id`s
+/// currently authorized to sign on that identity's behalf.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustManifest {
+    /// Monotonically increasing; [`TrustRoot::update`] rejects a candidate
+    /// manifest whose version isn't strictly greater than the cached one.
+    pub version: u64,
+
+    /// When this manifest itself expires, independent of any individual
+    /// key's own validity window.
+    pub expires: DateTime<Utc>,
+
+    /// Identity URI -> currently-authorized `key_id`s.
+    pub delegations: HashMap<String, Vec<String>>,
+}
+
+impl TrustManifest {
+    /// An empty manifest at `version`, expiring at `expires`. Add entries
+    /// with [`Self::with_delegation`].
+    pub fn new(version: u64, expires: DateTime<Utc>) -> Self {
+        Self {
+            version,
+            expires,
+            delegations: HashMap::new(),
+        }
+    }
+
+    /// Authorize `key_ids` to sign on behalf of `identity`, replacing any
+    /// delegation already set for it.
+    pub fn with_delegation(mut self, identity: impl Into<String>, key_ids: Vec<String>) -> Self {
+        self.delegations.insert(identity.into(), key_ids);
+        self
+    }
+
+    /// Whether `key_id` is currently authorized to sign for `identity`.
+    pub fn is_delegated(&self, identity: &str, key_id: &str) -> bool {
+        match self.delegations.get(identity) {
+            Some(key_ids) => key_ids.iter().any(|k| k == key_id),
+            None => false,
+        }
+    }
+}
+
+/// A verified [`TrustManifest`], cached locally and only ever replaced by a
+/// strictly newer one. See the module docs for why [`Self::fetch`] can't
+/// actually reach a CDN in this build.
+#[derive(Debug, Clone)]
+pub struct TrustRoot {
+    manifest: TrustManifest,
+}
+
+impl TrustRoot {
+    /// Verify `signed`'s threshold signature against `root_keys`, check the
+    /// manifest hasn't expired as of `reference_time`, and adopt it.
+    pub fn from_signed(
+        signed: &SignedAttestation,
+        root_keys: &VerifierSet,
+        threshold: usize,
+        reference_time: DateTime<Utc>,
+    ) -> Result<Self> {
+        let result = verify_threshold(signed, root_keys, threshold);
+        if !result.valid {
+            return Err(MakotoError::InvalidAttestation(format!(
+                "trust manifest threshold signature check failed: {}",
+                result.messages.join("; ")
+            )));
+        }
+
+        let manifest: TrustManifest = signed.decode_payload()?;
+        if manifest.expires <= reference_time {
+            return Err(MakotoError::KeyExpired {
+                key_id: "trust-root".to_string(),
+                reason: format!("manifest version {} expired at {}", manifest.version, manifest.expires),
+            });
+        }
+
+        Ok(Self { manifest })
+    }
+
+    /// Parse a signed envelope from `json` and verify it via
+    /// [`Self::from_signed`] — the entry point for a manifest already
+    /// retrieved out of band (e.g. by a caller's own HTTP client).
+    pub fn from_json(
+        json: &str,
+        root_keys: &VerifierSet,
+        threshold: usize,
+        reference_time: DateTime<Utc>,
+    ) -> Result<Self> {
+        let signed: SignedAttestation = serde_json::from_str(json)?;
+        Self::from_signed(&signed, root_keys, threshold, reference_time)
+    }
+
+    /// Retrieve and verify the trust manifest published at `base_url`.
+    ///
+    /// Always returns [`MakotoError::KeyError`]: this tree has no HTTP
+    /// dependency to actually perform the fetch. See the module docs.
+    pub fn fetch(base_url: &str, _root_keys: &VerifierSet, _threshold: usize) -> Result<Self> {
+        Err(MakotoError::KeyError(format!(
+            "TrustRoot::fetch({base_url}) requires an HTTP client, which this build does not \
+             include; fetch the manifest JSON yourself and call TrustRoot::from_json"
+        )))
+    }
+
+    /// Replace the cached manifest with `candidate`, rejecting it unless it
+    /// verifies and its `version` is strictly greater than the one
+    /// currently cached (rollback protection).
+    pub fn update(
+        &mut self,
+        candidate: &SignedAttestation,
+        root_keys: &VerifierSet,
+        threshold: usize,
+        reference_time: DateTime<Utc>,
+    ) -> Result<()> {
+        let next = Self::from_signed(candidate, root_keys, threshold, reference_time)?;
+        if next.manifest.version <= self.manifest.version {
+            return Err(MakotoError::InvalidAttestation(format!(
+                "trust manifest version {} is not newer than the cached version {}",
+                next.manifest.version, self.manifest.version
+            )));
+        }
+        self.manifest = next.manifest;
+        Ok(())
+    }
+
+    /// The cached manifest's version.
+    pub fn version(&self) -> u64 {
+        self.manifest.version
+    }
+
+    /// When the cached manifest expires.
+    pub fn expires(&self) -> DateTime<Utc> {
+        self.manifest.expires
+    }
+
+    /// Whether `key_id` is currently delegated to sign for `identity`.
+    pub fn is_delegated(&self, identity: &str, key_id: &str) -> bool {
+        self.manifest.is_delegated(identity, key_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::{MakotoSigner, SignatureAlgorithm};
+    use chrono::Duration;
+
+    fn sample_manifest(version: u64, expires: DateTime<Utc>) -> TrustManifest {
+        TrustManifest::new(version, expires).with_delegation(
+            "https://expanso.io/collectors/iot-001",
+            vec!["key-abc".to_string()],
+        )
+    }
+
+    fn sign_manifest(manifest: &TrustManifest, signers: &[&MakotoSigner]) -> SignedAttestation {
+        let mut signed = SignedAttestation::sign(manifest, signers[0]).unwrap();
+        for signer in &signers[1..] {
+            signed.add_signature(signer).unwrap();
+        }
+        signed
+    }
+
+    fn root_keys(signers: &[&MakotoSigner]) -> VerifierSet {
+        signers.iter().fold(VerifierSet::new(), |set, signer| {
+            set.with_verifier(signer.verifying_key(), SignatureAlgorithm::EcdsaP256.label())
+        })
+    }
+
+    #[test]
+    fn test_from_signed_accepts_valid_threshold_signed_manifest() {
+        let root_a = MakotoSigner::generate();
+        let root_b = MakotoSigner::generate();
+        let manifest = sample_manifest(1, Utc::now() + Duration::days(30));
+        let signed = sign_manifest(&manifest, &[&root_a, &root_b]);
+        let keys = root_keys(&[&root_a, &root_b]);
+
+        let trust = TrustRoot::from_signed(&signed, &keys, 2, Utc::now()).unwrap();
+        assert_eq!(trust.version(), 1);
+        assert!(trust.is_delegated("https://expanso.io/collectors/iot-001", "key-abc"));
+        assert!(!trust.is_delegated("https://expanso.io/collectors/iot-001", "key-xyz"));
+    }
+
+    #[test]
+    fn test_from_signed_rejects_below_threshold_signatures() {
+        let root_a = MakotoSigner::generate();
+        let root_b = MakotoSigner::generate();
+        let manifest = sample_manifest(1, Utc::now() + Duration::days(30));
+        let signed = sign_manifest(&manifest, &[&root_a]);
+        let keys = root_keys(&[&root_a, &root_b]);
+
+        assert!(TrustRoot::from_signed(&signed, &keys, 2, Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_from_signed_rejects_expired_manifest() {
+        let root_a = MakotoSigner::generate();
+        let manifest = sample_manifest(1, Utc::now() - Duration::days(1));
+        let signed = sign_manifest(&manifest, &[&root_a]);
+        let keys = root_keys(&[&root_a]);
+
+        assert!(TrustRoot::from_signed(&signed, &keys, 1, Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_update_rejects_rollback_to_same_or_older_version() {
+        let root_a = MakotoSigner::generate();
+        let keys = root_keys(&[&root_a]);
+
+        let v1 = sample_manifest(2, Utc::now() + Duration::days(30));
+        let signed_v1 = sign_manifest(&v1, &[&root_a]);
+        let mut trust = TrustRoot::from_signed(&signed_v1, &keys, 1, Utc::now()).unwrap();
+
+        let stale = sample_manifest(2, Utc::now() + Duration::days(30));
+        let signed_stale = sign_manifest(&stale, &[&root_a]);
+        assert!(trust.update(&signed_stale, &keys, 1, Utc::now()).is_err());
+        assert_eq!(trust.version(), 2);
+    }
+
+    #[test]
+    fn test_update_accepts_strictly_newer_version() {
+        let root_a = MakotoSigner::generate();
+        let keys = root_keys(&[&root_a]);
+
+        let v1 = sample_manifest(1, Utc::now() + Duration::days(30));
+        let signed_v1 = sign_manifest(&v1, &[&root_a]);
+        let mut trust = TrustRoot::from_signed(&signed_v1, &keys, 1, Utc::now()).unwrap();
+
+        let v2 = sample_manifest(2, Utc::now() + Duration::days(60));
+        let signed_v2 = sign_manifest(&v2, &[&root_a]);
+        trust.update(&signed_v2, &keys, 1, Utc::now()).unwrap();
+        assert_eq!(trust.version(), 2);
+    }
+
+    #[test]
+    fn test_fetch_reports_no_http_client_available() {
+        let keys = VerifierSet::new();
+        assert!(TrustRoot::fetch("https://cdn.example.com/trust", &keys, 1).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! RFC 8785 JSON Canonicalization Scheme (JCS) serialization.
+//!
+//! Attestation signatures are computed over serialized JSON, so
+//! re-serializing the same logical document with a different key order or
+//! number formatting would silently produce different signed bytes. This
+//! module gives every [`crate::signing::SignedAttestation`] signer a single
+//! canonical encoding to sign, so any conformant JCS implementation
+//! reproduces the exact pre-hash input.
+
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+use crate::error::Result;
+
+/// Serialize `value` to RFC 8785 JCS canonical JSON: object members sorted
+/// by their UTF-16 code unit sequence, no insignificant whitespace, strings
+/// escaped minimally (only `"`, `\`, and control characters below `0x20`),
+/// and numbers formatted per the ECMAScript `Number.prototype.toString`
+/// shortest-round-trip rules.
+pub fn to_canonical_json<T: Serialize>(value: &T) -> Result<String> {
+    let json_value = serde_json::to_value(value)?;
+    let mut out = String::new();
+    write_canonical_value(&json_value, &mut out);
+    Ok(out)
+}
+
+fn write_canonical_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => out.push_str(&canonical_number(n)),
+        Value::String(s) => write_canonical_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_value(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            out.push('{');
+            // RFC 8785 sorts object members by the UTF-16 code unit
+            // sequence of their keys, not by Rust's default `str` ordering
+            // (which is by UTF-8 byte value) — these agree for all-ASCII
+            // keys, which is all this crate's attestation schemas use, but
+            // diverge for keys outside the Basic Multilingual Plane.
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort_by_key(|k| k.encode_utf16().collect::<Vec<u16>>());
+
+            for (i, key) in keys.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical_string(key, out);
+                out.push(':');
+                write_canonical_value(&map[*key], out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_canonical_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{0008}' => out.push_str("\\b"),
+            '\u{000C}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Format a JSON number per JCS: integers that fit in `i64`/`u64` print as
+/// plain decimal with no exponent; everything else falls back to Rust's
+/// shortest-round-trip `f64` formatting, which agrees with ECMAScript's
+/// `Number.prototype.toString` for the ranges this crate's attestation
+/// fields actually use (small integers and simple fractional values), with
+/// a trailing `.0` stripped since JCS has no such suffix for integral
+/// floats.
+fn canonical_number(n: &Number) -> String {
+    if let Some(i) = n.as_i64() {
+        return i.to_string();
+    }
+    if let Some(u) = n.as_u64() {
+        return u.to_string();
+    }
+
+    let f = n.as_f64().unwrap_or(0.0);
+    let s = format!("{}", f);
+    s.strip_suffix(".0").map(str::to_string).unwrap_or(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_canonical_json_sorts_object_keys() {
+        let value = json!({"b": 1, "a": 2});
+        assert_eq!(to_canonical_json(&value).unwrap(), r#"{"a":2,"b":1}"#);
+    }
+
+    #[test]
+    fn test_canonical_json_has_no_insignificant_whitespace() {
+        let value = json!({"a": [1, 2, 3], "b": "x"});
+        let canonical = to_canonical_json(&value).unwrap();
+        assert!(!canonical.contains(' '));
+        assert_eq!(canonical, r#"{"a":[1,2,3],"b":"x"}"#);
+    }
+
+    #[test]
+    fn test_canonical_json_escapes_control_characters() {
+        let value = json!({"s": "line1\nline2\ttabbed"});
+        assert_eq!(
+            to_canonical_json(&value).unwrap(),
+            r#"{"s":"line1\nline2\ttabbed"}"#
+        );
+    }
+
+    #[test]
+    fn test_canonical_json_is_deterministic_across_insertion_order() {
+        let a = json!({"z": 1, "m": 2, "a": 3});
+        let b = json!({"a": 3, "z": 1, "m": 2});
+        assert_eq!(to_canonical_json(&a).unwrap(), to_canonical_json(&b).unwrap());
+    }
+
+    #[test]
+    fn test_canonical_json_nested_objects_sort_independently() {
+        let value = json!({"outer": {"b": 1, "a": 2}});
+        assert_eq!(
+            to_canonical_json(&value).unwrap(),
+            r#"{"outer":{"a":2,"b":1}}"#
+        );
+    }
+
+    #[test]
+    fn test_canonical_json_integers_have_no_trailing_fraction() {
+        let value = json!({"n": 5});
+        assert_eq!(to_canonical_json(&value).unwrap(), r#"{"n":5}"#);
+    }
+}
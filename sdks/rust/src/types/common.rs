@@ -7,7 +7,10 @@ use std::collections::HashMap;
 pub const IN_TOTO_STATEMENT_TYPE: &str = "https://in-toto.io/Statement/v1";
 
 /// Makoto attestation levels.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Ordered by increasing trust (`L1 < L2 < L3`) so callers can compute the
+/// weakest level across a set of attestations with a plain `min()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum MakotoLevel {
     /// Level 1: Attestation exists (provenance available).
     L1,
@@ -192,7 +195,7 @@ pub enum ConfidentialityClassification {
 }
 
 /// Hash algorithm identifiers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum HashAlgorithm {
     Sha256,
@@ -206,6 +209,10 @@ pub enum HashAlgorithm {
     Sha3_512,
     Blake2b,
     Blake3,
+    /// Keccak-256 (the EVM's `SHA3`), for attestation roots verified on
+    /// chain by a Solidity contract.
+    #[serde(rename = "keccak-256")]
+    Keccak256,
 }
 
 impl Default for HashAlgorithm {
@@ -3,6 +3,7 @@
 use super::common::*;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// A comprehensive manifest documenting dataset provenance and lineage.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -64,6 +65,130 @@ impl Dbom {
 
         Ok(())
     }
+
+    /// Deep structural validation of the lineage DAG formed by `sources`,
+    /// `transformations`, and the final `dataset`, beyond the shallow
+    /// checks in [`Dbom::validate`]. Accumulates every violation rather
+    /// than stopping at the first, so producers get actionable feedback on
+    /// malformed lineage.
+    ///
+    /// Checks:
+    /// - every `Transformation.input` names a declared `Source` or the
+    ///   `output` of a transformation with a strictly earlier `order` —
+    ///   since transformations are only allowed to consume what an earlier
+    ///   step already produced, this also rules out cycles (nothing can
+    ///   consume its own or a later step's output), which is what "cycle
+    ///   detection via topological sort over order" amounts to here;
+    /// - the final `dataset.name` is reachable as some transformation's
+    ///   output (only enforced when transformations are present — with
+    ///   none, the sources directly *are* the dataset);
+    /// - every transformation output that feeds nothing and isn't the
+    ///   final dataset is flagged as dangling;
+    /// - `Contribution.record_percentage` across sources sums to ~100%
+    ///   (within a 0.5 percentage-point tolerance), when present;
+    /// - `Contribution.record_count` across sources sums to
+    ///   `dataset.digest.record_count`, when both are present.
+    pub fn validate_dag(&self) -> crate::error::Result<()> {
+        let mut issues = Vec::new();
+
+        let mut produced: HashSet<&str> = self.sources.iter().map(|s| s.name.as_str()).collect();
+        let mut consumed: HashSet<&str> = HashSet::new();
+
+        let mut steps: Vec<&Transformation> = self.transformations.iter().flatten().collect();
+        steps.sort_by_key(|t| t.order);
+
+        for transformation in &steps {
+            for input in &transformation.inputs {
+                if !produced.contains(input.as_str()) {
+                    issues.push(format!(
+                        "transformation '{}' (order {}) consumes input '{}', which is not a \
+                         declared source or the output of an earlier transformation",
+                        transformation.name, transformation.order, input
+                    ));
+                }
+                consumed.insert(input.as_str());
+            }
+            for output in &transformation.outputs {
+                produced.insert(output.as_str());
+            }
+        }
+
+        if !steps.is_empty() {
+            let produces_final = steps
+                .iter()
+                .any(|t| t.outputs.iter().any(|o| o == &self.dataset.name));
+            if !produces_final {
+                issues.push(format!(
+                    "final dataset '{}' is not produced as the output of any transformation",
+                    self.dataset.name
+                ));
+            }
+        }
+
+        for transformation in &steps {
+            for output in &transformation.outputs {
+                if output != &self.dataset.name && !consumed.contains(output.as_str()) {
+                    issues.push(format!(
+                        "transformation '{}' (order {}) produces output '{}', which feeds no \
+                         later transformation and is not the final dataset",
+                        transformation.name, transformation.order, output
+                    ));
+                }
+            }
+        }
+
+        let percentages: Vec<f64> = self
+            .sources
+            .iter()
+            .filter_map(|s| s.contribution.as_ref()?.record_percentage)
+            .collect();
+        if !percentages.is_empty() {
+            let total: f64 = percentages.iter().sum();
+            if (total - 100.0).abs() > 0.5 {
+                issues.push(format!(
+                    "source contribution percentages sum to {:.2}%, expected ~100%",
+                    total
+                ));
+            }
+        }
+
+        let record_counts: Vec<u64> = self
+            .sources
+            .iter()
+            .filter_map(|s| s.contribution.as_ref()?.record_count)
+            .collect();
+        if !record_counts.is_empty() {
+            if let Some(expected) = self
+                .dataset
+                .digest
+                .record_count
+                .as_ref()
+                .and_then(record_count_as_u64)
+            {
+                let total: u64 = record_counts.iter().sum();
+                if total != expected {
+                    issues.push(format!(
+                        "source contribution record counts sum to {}, but dataset digest \
+                         records {}",
+                        total, expected
+                    ));
+                }
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::error::MakotoError::DagValidationError(issues))
+        }
+    }
+}
+
+fn record_count_as_u64(count: &RecordCount) -> Option<u64> {
+    match count {
+        RecordCount::Integer(n) => Some(*n),
+        RecordCount::String(s) => s.parse().ok(),
+    }
 }
 
 /// Builder for creating DBOMs.
@@ -435,6 +560,17 @@ pub struct Transformation {
     /// Transform type URI.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transform_type: Option<String>,
+
+    /// Remote-attestation evidence that this transformation ran inside a
+    /// confidential-compute enclave.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tee_evidence: Option<TeeEvidence>,
+
+    /// Differential-privacy parameters for this step, if it applies a DP
+    /// mechanism. Composed across the whole chain by
+    /// [`crate::privacy::compose_privacy_budget`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub differential_privacy: Option<DifferentialPrivacy>,
 }
 
 impl Transformation {
@@ -457,8 +593,82 @@ impl Transformation {
             inputs,
             outputs,
             transform_type: None,
+            differential_privacy: None,
+            tee_evidence: None,
         }
     }
+
+    /// Attach TEE/enclave attestation evidence.
+    pub fn with_tee_evidence(mut self, tee_evidence: TeeEvidence) -> Self {
+        self.tee_evidence = Some(tee_evidence);
+        self
+    }
+
+    /// Attach this step's differential-privacy parameters.
+    pub fn with_differential_privacy(mut self, differential_privacy: DifferentialPrivacy) -> Self {
+        self.differential_privacy = Some(differential_privacy);
+        self
+    }
+}
+
+/// TEE/enclave remote-attestation evidence for a [`Transformation`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TeeEvidence {
+    /// Confidential-compute technology that produced the quote.
+    pub enclave_type: EnclaveType,
+
+    /// Base64-encoded remote-attestation quote/report.
+    pub quote: String,
+
+    /// Launch measurement (MRENCLAVE/MRSIGNER or equivalent launch digest).
+    pub measurement: String,
+
+    /// Runtime data hash, expected to match an input digest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime_data_hash: Option<String>,
+
+    /// Init-time data hash, expected to match an input digest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub init_data_hash: Option<String>,
+}
+
+impl TeeEvidence {
+    /// Create new TEE evidence.
+    pub fn new(
+        enclave_type: EnclaveType,
+        quote: impl Into<String>,
+        measurement: impl Into<String>,
+    ) -> Self {
+        Self {
+            enclave_type,
+            quote: quote.into(),
+            measurement: measurement.into(),
+            runtime_data_hash: None,
+            init_data_hash: None,
+        }
+    }
+
+    /// Set the runtime data hash.
+    pub fn with_runtime_data_hash(mut self, hash: impl Into<String>) -> Self {
+        self.runtime_data_hash = Some(hash.into());
+        self
+    }
+
+    /// Set the init-time data hash.
+    pub fn with_init_data_hash(mut self, hash: impl Into<String>) -> Self {
+        self.init_data_hash = Some(hash.into());
+        self
+    }
+}
+
+/// Confidential-compute enclave technologies recognized by [`TeeEvidence`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EnclaveType {
+    Sgx,
+    SevSnp,
+    Tdx,
 }
 
 /// Lineage graph representation.
@@ -629,6 +839,68 @@ pub struct DbomVerification {
     pub errors: Option<Vec<VerificationError>>,
 }
 
+impl DbomVerification {
+    /// Structurally verify every `Transformation.tee_evidence` in `dbom`
+    /// against `allowed_measurements`.
+    ///
+    /// A transformation passes if it carries `tee_evidence` with a non-empty
+    /// quote and a measurement present in `allowed_measurements`. Each
+    /// failure (missing evidence, empty quote, or unlisted measurement)
+    /// records one [`VerificationError`] with a dedicated `code`.
+    pub fn verify_tee_evidence(dbom: &Dbom, allowed_measurements: &[String]) -> Self {
+        let mut errors = Vec::new();
+        let mut checked = 0u32;
+
+        for transformation in dbom.transformations.iter().flatten() {
+            checked += 1;
+
+            let evidence = match &transformation.tee_evidence {
+                Some(evidence) => evidence,
+                None => {
+                    errors.push(VerificationError {
+                        code: Some("TEE_EVIDENCE_MISSING".to_string()),
+                        message: Some(format!(
+                            "transformation '{}' has no tee_evidence",
+                            transformation.name
+                        )),
+                        attestation_ref: transformation.attestation_ref.clone(),
+                    });
+                    continue;
+                }
+            };
+
+            if evidence.quote.is_empty() {
+                errors.push(VerificationError {
+                    code: Some("TEE_EVIDENCE_INVALID".to_string()),
+                    message: Some(format!(
+                        "transformation '{}' has an empty TEE quote",
+                        transformation.name
+                    )),
+                    attestation_ref: transformation.attestation_ref.clone(),
+                });
+            } else if !allowed_measurements.contains(&evidence.measurement) {
+                errors.push(VerificationError {
+                    code: Some("TEE_EVIDENCE_INVALID".to_string()),
+                    message: Some(format!(
+                        "transformation '{}' measurement '{}' is not in the allowlist",
+                        transformation.name, evidence.measurement
+                    )),
+                    attestation_ref: transformation.attestation_ref.clone(),
+                });
+            }
+        }
+
+        Self {
+            chain_verified: None,
+            all_signatures_valid: None,
+            attestation_count: Some(checked),
+            verification_timestamp: Some(Utc::now()),
+            verifier: None,
+            errors: if errors.is_empty() { None } else { Some(errors) },
+        }
+    }
+}
+
 /// Verifier tool info.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VerifierInfo {
@@ -789,4 +1061,216 @@ mod tests {
 
         assert!(dbom.validate().is_err());
     }
+
+    fn dbom_with_transformation(tee_evidence: Option<TeeEvidence>) -> Dbom {
+        let dataset = DatasetInfo::new(
+            "test-dataset",
+            "1.0.0",
+            Utc::now(),
+            DbomDigest::new("a".repeat(64)),
+            MakotoLevel::L2,
+        );
+        let source = Source::new("source_data", "https://makoto.dev/origin/v1", MakotoLevel::L2);
+        let mut transformation = Transformation::new(
+            1,
+            "redact_pii",
+            "https://makoto.dev/transform/v1",
+            MakotoLevel::L2,
+            vec!["source_data".to_string()],
+            vec!["test-dataset".to_string()],
+        );
+        if let Some(evidence) = tee_evidence {
+            transformation = transformation.with_tee_evidence(evidence);
+        }
+
+        Dbom::builder()
+            .id("urn:dbom:test:dataset-v1")
+            .dataset(dataset)
+            .source(source)
+            .transformation(transformation)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_tee_evidence_passes_allowlisted_measurement() {
+        let evidence = TeeEvidence::new(EnclaveType::SevSnp, "base64quote", "deadbeef");
+        let dbom = dbom_with_transformation(Some(evidence));
+
+        let verification =
+            DbomVerification::verify_tee_evidence(&dbom, &["deadbeef".to_string()]);
+
+        assert_eq!(verification.attestation_count, Some(1));
+        assert!(verification.errors.is_none());
+    }
+
+    #[test]
+    fn test_verify_tee_evidence_rejects_measurement_not_in_allowlist() {
+        let evidence = TeeEvidence::new(EnclaveType::Sgx, "base64quote", "unknown-measurement");
+        let dbom = dbom_with_transformation(Some(evidence));
+
+        let verification =
+            DbomVerification::verify_tee_evidence(&dbom, &["deadbeef".to_string()]);
+
+        let errors = verification.errors.unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("TEE_EVIDENCE_INVALID"));
+    }
+
+    #[test]
+    fn test_validate_dag_passes_for_connected_chain() {
+        let dataset = DatasetInfo::new(
+            "final-dataset",
+            "1.0.0",
+            Utc::now(),
+            DbomDigest::new("a".repeat(64)),
+            MakotoLevel::L2,
+        );
+        let source = Source::new("raw_data", "https://makoto.dev/origin/v1", MakotoLevel::L2);
+        let transformation = Transformation::new(
+            1,
+            "clean",
+            "https://makoto.dev/transform/v1",
+            MakotoLevel::L2,
+            vec!["raw_data".to_string()],
+            vec!["final-dataset".to_string()],
+        );
+
+        let dbom = Dbom::builder()
+            .id("urn:dbom:example.com:final-v1")
+            .dataset(dataset)
+            .source(source)
+            .transformation(transformation)
+            .build()
+            .unwrap();
+
+        assert!(dbom.validate_dag().is_ok());
+    }
+
+    #[test]
+    fn test_validate_dag_flags_unproduced_input() {
+        let dataset = DatasetInfo::new(
+            "final-dataset",
+            "1.0.0",
+            Utc::now(),
+            DbomDigest::new("a".repeat(64)),
+            MakotoLevel::L2,
+        );
+        let source = Source::new("raw_data", "https://makoto.dev/origin/v1", MakotoLevel::L2);
+        let transformation = Transformation::new(
+            1,
+            "clean",
+            "https://makoto.dev/transform/v1",
+            MakotoLevel::L2,
+            vec!["nonexistent_source".to_string()],
+            vec!["final-dataset".to_string()],
+        );
+
+        let dbom = Dbom::builder()
+            .id("urn:dbom:example.com:final-v1")
+            .dataset(dataset)
+            .source(source)
+            .transformation(transformation)
+            .build()
+            .unwrap();
+
+        let err = dbom.validate_dag().unwrap_err();
+        match err {
+            crate::error::MakotoError::DagValidationError(issues) => {
+                assert_eq!(issues.len(), 1);
+                assert!(issues[0].contains("nonexistent_source"));
+            }
+            other => panic!("expected DagValidationError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_dag_flags_dangling_output_and_unreached_final() {
+        let dataset = DatasetInfo::new(
+            "final-dataset",
+            "1.0.0",
+            Utc::now(),
+            DbomDigest::new("a".repeat(64)),
+            MakotoLevel::L2,
+        );
+        let source = Source::new("raw_data", "https://makoto.dev/origin/v1", MakotoLevel::L2);
+        let transformation = Transformation::new(
+            1,
+            "clean",
+            "https://makoto.dev/transform/v1",
+            MakotoLevel::L2,
+            vec!["raw_data".to_string()],
+            vec!["intermediate".to_string()],
+        );
+
+        let dbom = Dbom::builder()
+            .id("urn:dbom:example.com:final-v1")
+            .dataset(dataset)
+            .source(source)
+            .transformation(transformation)
+            .build()
+            .unwrap();
+
+        let err = dbom.validate_dag().unwrap_err();
+        match err {
+            crate::error::MakotoError::DagValidationError(issues) => {
+                assert!(issues.iter().any(|i| i.contains("not produced")));
+                assert!(issues.iter().any(|i| i.contains("feeds no")));
+            }
+            other => panic!("expected DagValidationError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_dag_checks_contribution_percentages_and_counts() {
+        let dataset = DatasetInfo::new(
+            "final-dataset",
+            "1.0.0",
+            Utc::now(),
+            DbomDigest {
+                record_count: Some(RecordCount::Integer(100)),
+                ..DbomDigest::new("a".repeat(64))
+            },
+            MakotoLevel::L2,
+        );
+
+        let mut source_a = Source::new("source_a", "https://makoto.dev/origin/v1", MakotoLevel::L2);
+        source_a.contribution = Some(Contribution {
+            record_count: Some(40),
+            record_percentage: Some(40.0),
+        });
+        let mut source_b = Source::new("source_b", "https://makoto.dev/origin/v1", MakotoLevel::L2);
+        source_b.contribution = Some(Contribution {
+            record_count: Some(40),
+            record_percentage: Some(40.0),
+        });
+
+        let dbom = Dbom::builder()
+            .id("urn:dbom:example.com:final-v1")
+            .dataset(dataset)
+            .source(source_a)
+            .source(source_b)
+            .build()
+            .unwrap();
+
+        let err = dbom.validate_dag().unwrap_err();
+        match err {
+            crate::error::MakotoError::DagValidationError(issues) => {
+                assert!(issues.iter().any(|i| i.contains("80.00%")));
+                assert!(issues.iter().any(|i| i.contains("sum to 80")));
+            }
+            other => panic!("expected DagValidationError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verify_tee_evidence_records_missing_evidence() {
+        let dbom = dbom_with_transformation(None);
+
+        let verification = DbomVerification::verify_tee_evidence(&dbom, &[]);
+
+        let errors = verification.errors.unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code.as_deref(), Some("TEE_EVIDENCE_MISSING"));
+    }
 }
@@ -3,15 +3,26 @@
 //! This module contains all the type definitions for Makoto attestations,
 //! matching the JSON schemas defined in the specification.
 
+pub mod canonical;
 pub mod common;
 pub mod dbom;
 pub mod origin;
+pub mod scai;
+pub mod slsa;
+pub mod statement;
 pub mod stream_window;
 pub mod transform;
 
 // Re-export commonly used types at the module level
+pub use canonical::to_canonical_json;
 pub use common::*;
 pub use dbom::Dbom;
 pub use origin::{OriginAttestation, OriginPredicate, ORIGIN_PREDICATE_TYPE};
+pub use scai::{ScaiAttestation, ScaiAttribute, ScaiPredicate, SCAI_PREDICATE_TYPE};
+pub use slsa::{
+    SlsaProvenanceV02Attestation, SlsaProvenanceV02Predicate, SlsaProvenanceV1Attestation,
+    SlsaProvenanceV1Predicate, SLSA_PROVENANCE_V02_PREDICATE_TYPE, SLSA_PROVENANCE_V1_PREDICATE_TYPE,
+};
+pub use statement::RawStatement;
 pub use stream_window::{StreamWindowAttestation, StreamWindowPredicate, STREAM_WINDOW_PREDICATE_TYPE};
 pub use transform::{TransformAttestation, TransformPredicate, TRANSFORM_PREDICATE_TYPE};
@@ -297,7 +297,7 @@ impl DataSchema {
 }
 
 /// Collection statistics and metrics.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CollectionMetadata {
     /// ISO 8601 duration of the collection process.
@@ -329,20 +329,6 @@ pub struct CollectionMetadata {
     pub end_time: Option<DateTime<Utc>>,
 }
 
-impl Default for CollectionMetadata {
-    fn default() -> Self {
-        Self {
-            collection_duration: None,
-            bytes_collected: None,
-            records_collected: None,
-            records_dropped: None,
-            error_rate: None,
-            start_time: None,
-            end_time: None,
-        }
-    }
-}
-
 /// D&TA Data Provenance Standards compliance.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,164 @@
+//! SCAI (Supply Chain Attribute Integrity) predicate types, for Makoto
+//! DBOMs that embed or reference attribute-level claims about the code
+//! that produced a dataset.
+//!
+//! This models the upstream [SCAI v0.2](https://github.com/in-toto/attestation/blob/main/spec/predicates/scai.md)
+//! schema, not a Makoto-defined predicate, so `conditions` (which is
+//! attribute-specific and open-ended per the spec) is carried as a raw
+//! [`serde_json::Value`].
+
+use super::common::IN_TOTO_STATEMENT_TYPE;
+use super::slsa::ResourceDescriptor;
+use super::Subject;
+use serde::{Deserialize, Serialize};
+
+/// Predicate type URI for SCAI v0.2.
+pub const SCAI_PREDICATE_TYPE: &str = "https://in-toto.io/attestation/scai/attribute-report/v0.2";
+
+/// SCAI attestation (in-toto Statement with a SCAI v0.2 predicate).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaiAttestation {
+    /// in-toto Statement type identifier.
+    #[serde(rename = "_type")]
+    pub statement_type: String,
+
+    /// The artifact(s) this attribute report describes.
+    pub subject: Vec<Subject>,
+
+    /// Predicate type identifier.
+    pub predicate_type: String,
+
+    /// The SCAI attribute-report predicate.
+    pub predicate: ScaiPredicate,
+}
+
+impl ScaiAttestation {
+    /// Validate the attestation structure.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        if self.statement_type != IN_TOTO_STATEMENT_TYPE {
+            return Err(crate::error::MakotoError::InvalidAttestation(format!(
+                "Invalid statement type: expected {}, got {}",
+                IN_TOTO_STATEMENT_TYPE, self.statement_type
+            )));
+        }
+
+        if self.predicate_type != SCAI_PREDICATE_TYPE {
+            return Err(crate::error::MakotoError::InvalidPredicateType {
+                expected: SCAI_PREDICATE_TYPE.to_string(),
+                actual: self.predicate_type.clone(),
+            });
+        }
+
+        if self.subject.is_empty() {
+            return Err(crate::error::MakotoError::MissingField(
+                "subject".to_string(),
+            ));
+        }
+
+        if self.predicate.attributes.is_empty() {
+            return Err(crate::error::MakotoError::MissingField(
+                "predicate.attributes".to_string(),
+            ));
+        }
+
+        for (i, attribute) in self.predicate.attributes.iter().enumerate() {
+            if attribute.attribute.is_empty() {
+                return Err(crate::error::MakotoError::MissingField(format!(
+                    "predicate.attributes[{}].attribute",
+                    i
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// SCAI v0.2 predicate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaiPredicate {
+    /// The claims being made about the target(s).
+    pub attributes: Vec<ScaiAttribute>,
+
+    /// The entity that generated the attribute assertions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub producer: Option<ResourceDescriptor>,
+}
+
+/// A single SCAI attribute assertion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScaiAttribute {
+    /// Name of the attribute being asserted (e.g. `TRUSTED_BUILD_SYSTEM`).
+    pub attribute: String,
+
+    /// The artifact the attribute is being asserted about, if not the
+    /// attestation's own `subject`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<ResourceDescriptor>,
+
+    /// Attribute-specific conditions under which the assertion holds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<serde_json::Value>,
+
+    /// Confidence that the assertion is true, in `[0.0, 1.0]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+
+    /// Supporting evidence for the assertion.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<ResourceDescriptor>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Digest;
+
+    fn sample() -> ScaiAttestation {
+        ScaiAttestation {
+            statement_type: IN_TOTO_STATEMENT_TYPE.to_string(),
+            subject: vec![Subject::new("dataset:test", Digest::new("a".repeat(64)))],
+            predicate_type: SCAI_PREDICATE_TYPE.to_string(),
+            predicate: ScaiPredicate {
+                attributes: vec![ScaiAttribute {
+                    attribute: "TRUSTED_BUILD_SYSTEM".to_string(),
+                    target: None,
+                    conditions: None,
+                    confidence: Some(0.9),
+                    evidence: None,
+                }],
+                producer: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_scai_validate_passes() {
+        assert!(sample().validate().is_ok());
+    }
+
+    #[test]
+    fn test_scai_validate_rejects_empty_attributes() {
+        let mut attestation = sample();
+        attestation.predicate.attributes.clear();
+        assert!(attestation.validate().is_err());
+    }
+
+    #[test]
+    fn test_scai_validate_rejects_unnamed_attribute() {
+        let mut attestation = sample();
+        attestation.predicate.attributes[0].attribute = String::new();
+        assert!(attestation.validate().is_err());
+    }
+
+    #[test]
+    fn test_scai_serialization_round_trip() {
+        let attestation = sample();
+        let json = serde_json::to_string(&attestation).unwrap();
+        let parsed: ScaiAttestation = serde_json::from_str(&json).unwrap();
+        assert_eq!(attestation, parsed);
+    }
+}
@@ -0,0 +1,323 @@
+//! SLSA Provenance predicate types, for Makoto DBOMs that embed or
+//! reference build provenance for the code that produced a dataset.
+//!
+//! This models the upstream [SLSA Provenance v0.2](https://slsa.dev/provenance/v0.2)
+//! and [v1](https://slsa.dev/provenance/v1) schemas, not a Makoto-defined
+//! predicate, so fields with genuinely open schemas in the spec (build
+//! parameters, environment, byproducts) are carried as raw
+//! [`serde_json::Value`] rather than modeled field-by-field.
+
+use super::common::IN_TOTO_STATEMENT_TYPE;
+use super::Subject;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Predicate type URI for SLSA Provenance v0.2.
+pub const SLSA_PROVENANCE_V02_PREDICATE_TYPE: &str = "https://slsa.dev/provenance/v0.2";
+
+/// Predicate type URI for SLSA Provenance v1.
+pub const SLSA_PROVENANCE_V1_PREDICATE_TYPE: &str = "https://slsa.dev/provenance/v1";
+
+/// A set of digests keyed by algorithm name (in-toto's `DigestSet`), as used
+/// by externally-defined predicates whose digest fields aren't constrained
+/// to Makoto's own [`super::Digest`] shape.
+pub type DigestSet = HashMap<String, String>;
+
+/// An artifact reference used throughout SLSA provenance (`materials` in
+/// v0.2, `resolvedDependencies`/`builderDependencies` in v1).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceDescriptor {
+    /// URI identifying the resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+
+    /// Digests of the resource's contents.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub digest: DigestSet,
+}
+
+/// SLSA Provenance v0.2 attestation (in-toto Statement with a
+/// `slsa.dev/provenance/v0.2` predicate).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlsaProvenanceV02Attestation {
+    /// in-toto Statement type identifier.
+    #[serde(rename = "_type")]
+    pub statement_type: String,
+
+    /// The artifact(s) this provenance describes.
+    pub subject: Vec<Subject>,
+
+    /// Predicate type identifier.
+    pub predicate_type: String,
+
+    /// The SLSA v0.2 provenance predicate.
+    pub predicate: SlsaProvenanceV02Predicate,
+}
+
+impl SlsaProvenanceV02Attestation {
+    /// Validate the attestation structure.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        if self.statement_type != IN_TOTO_STATEMENT_TYPE {
+            return Err(crate::error::MakotoError::InvalidAttestation(format!(
+                "Invalid statement type: expected {}, got {}",
+                IN_TOTO_STATEMENT_TYPE, self.statement_type
+            )));
+        }
+
+        if self.predicate_type != SLSA_PROVENANCE_V02_PREDICATE_TYPE {
+            return Err(crate::error::MakotoError::InvalidPredicateType {
+                expected: SLSA_PROVENANCE_V02_PREDICATE_TYPE.to_string(),
+                actual: self.predicate_type.clone(),
+            });
+        }
+
+        if self.subject.is_empty() {
+            return Err(crate::error::MakotoError::MissingField(
+                "subject".to_string(),
+            ));
+        }
+
+        if self.predicate.builder.id.is_empty() {
+            return Err(crate::error::MakotoError::MissingField(
+                "predicate.builder.id".to_string(),
+            ));
+        }
+
+        if self.predicate.build_type.is_empty() {
+            return Err(crate::error::MakotoError::MissingField(
+                "predicate.buildType".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// SLSA v0.2 provenance predicate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlsaProvenanceV02Predicate {
+    /// The entity that executed the build.
+    pub builder: SlsaBuilder,
+
+    /// URI identifying the template of the build that produced the artifact.
+    pub build_type: String,
+
+    /// Identifies the event that kicked off the build.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invocation: Option<serde_json::Value>,
+
+    /// Parameters used in the build that are not part of `invocation`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_config: Option<serde_json::Value>,
+
+    /// Other properties of the build.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+
+    /// The material (e.g. source code checkout) used to build the artifact.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub materials: Vec<ResourceDescriptor>,
+}
+
+/// The entity that executed a SLSA build.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlsaBuilder {
+    /// URI identifying the builder.
+    pub id: String,
+}
+
+/// SLSA Provenance v1 attestation (in-toto Statement with a
+/// `slsa.dev/provenance/v1` predicate).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlsaProvenanceV1Attestation {
+    /// in-toto Statement type identifier.
+    #[serde(rename = "_type")]
+    pub statement_type: String,
+
+    /// The artifact(s) this provenance describes.
+    pub subject: Vec<Subject>,
+
+    /// Predicate type identifier.
+    pub predicate_type: String,
+
+    /// The SLSA v1 provenance predicate.
+    pub predicate: SlsaProvenanceV1Predicate,
+}
+
+impl SlsaProvenanceV1Attestation {
+    /// Validate the attestation structure.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        if self.statement_type != IN_TOTO_STATEMENT_TYPE {
+            return Err(crate::error::MakotoError::InvalidAttestation(format!(
+                "Invalid statement type: expected {}, got {}",
+                IN_TOTO_STATEMENT_TYPE, self.statement_type
+            )));
+        }
+
+        if self.predicate_type != SLSA_PROVENANCE_V1_PREDICATE_TYPE {
+            return Err(crate::error::MakotoError::InvalidPredicateType {
+                expected: SLSA_PROVENANCE_V1_PREDICATE_TYPE.to_string(),
+                actual: self.predicate_type.clone(),
+            });
+        }
+
+        if self.subject.is_empty() {
+            return Err(crate::error::MakotoError::MissingField(
+                "subject".to_string(),
+            ));
+        }
+
+        if self.predicate.build_definition.build_type.is_empty() {
+            return Err(crate::error::MakotoError::MissingField(
+                "predicate.buildDefinition.buildType".to_string(),
+            ));
+        }
+
+        if self.predicate.run_details.builder.id.is_empty() {
+            return Err(crate::error::MakotoError::MissingField(
+                "predicate.runDetails.builder.id".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// SLSA v1 provenance predicate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlsaProvenanceV1Predicate {
+    /// Describes where the build came from and how it was executed.
+    pub build_definition: SlsaBuildDefinition,
+
+    /// Describes the execution of the build.
+    pub run_details: SlsaRunDetails,
+}
+
+/// SLSA v1 `buildDefinition`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlsaBuildDefinition {
+    /// URI identifying the template of the build that produced the artifact.
+    pub build_type: String,
+
+    /// Parameters under control of the external party kicking off the build.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_parameters: Option<serde_json::Value>,
+
+    /// Parameters under the builder's control.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_parameters: Option<serde_json::Value>,
+
+    /// Artifacts the build depended on, in addition to `externalParameters`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resolved_dependencies: Vec<ResourceDescriptor>,
+}
+
+/// SLSA v1 `runDetails`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlsaRunDetails {
+    /// The entity that executed the build.
+    pub builder: SlsaBuilder,
+
+    /// Other properties of the build not captured elsewhere.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+
+    /// Additional artifacts generated during the build not in `subject`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub byproducts: Vec<ResourceDescriptor>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Digest;
+
+    fn sample_v02() -> SlsaProvenanceV02Attestation {
+        SlsaProvenanceV02Attestation {
+            statement_type: IN_TOTO_STATEMENT_TYPE.to_string(),
+            subject: vec![Subject::new("dataset:test", Digest::new("a".repeat(64)))],
+            predicate_type: SLSA_PROVENANCE_V02_PREDICATE_TYPE.to_string(),
+            predicate: SlsaProvenanceV02Predicate {
+                builder: SlsaBuilder {
+                    id: "https://ci.example.com/builder/1".to_string(),
+                },
+                build_type: "https://ci.example.com/build-types/default".to_string(),
+                invocation: None,
+                build_config: None,
+                metadata: None,
+                materials: vec![],
+            },
+        }
+    }
+
+    fn sample_v1() -> SlsaProvenanceV1Attestation {
+        SlsaProvenanceV1Attestation {
+            statement_type: IN_TOTO_STATEMENT_TYPE.to_string(),
+            subject: vec![Subject::new("dataset:test", Digest::new("a".repeat(64)))],
+            predicate_type: SLSA_PROVENANCE_V1_PREDICATE_TYPE.to_string(),
+            predicate: SlsaProvenanceV1Predicate {
+                build_definition: SlsaBuildDefinition {
+                    build_type: "https://ci.example.com/build-types/default".to_string(),
+                    external_parameters: None,
+                    internal_parameters: None,
+                    resolved_dependencies: vec![],
+                },
+                run_details: SlsaRunDetails {
+                    builder: SlsaBuilder {
+                        id: "https://ci.example.com/builder/1".to_string(),
+                    },
+                    metadata: None,
+                    byproducts: vec![],
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn test_slsa_v02_validate_passes() {
+        assert!(sample_v02().validate().is_ok());
+    }
+
+    #[test]
+    fn test_slsa_v02_validate_rejects_wrong_predicate_type() {
+        let mut attestation = sample_v02();
+        attestation.predicate_type = "https://example.com/other".to_string();
+        assert!(attestation.validate().is_err());
+    }
+
+    #[test]
+    fn test_slsa_v1_validate_passes() {
+        assert!(sample_v1().validate().is_ok());
+    }
+
+    #[test]
+    fn test_slsa_v1_validate_rejects_empty_builder_id() {
+        let mut attestation = sample_v1();
+        attestation.predicate.run_details.builder.id = String::new();
+        assert!(attestation.validate().is_err());
+    }
+
+    #[test]
+    fn test_slsa_v02_serialization_round_trip() {
+        let attestation = sample_v02();
+        let json = serde_json::to_string(&attestation).unwrap();
+        let parsed: SlsaProvenanceV02Attestation = serde_json::from_str(&json).unwrap();
+        assert_eq!(attestation, parsed);
+    }
+
+    #[test]
+    fn test_slsa_v1_serialization_round_trip() {
+        let attestation = sample_v1();
+        let json = serde_json::to_string(&attestation).unwrap();
+        let parsed: SlsaProvenanceV1Attestation = serde_json::from_str(&json).unwrap();
+        assert_eq!(attestation, parsed);
+    }
+}
@@ -0,0 +1,76 @@
+//! Generic in-toto Statement layer, for predicate types this SDK consumes
+//! but does not itself model (i.e. anything other than its own Makoto
+//! predicates and the SLSA/SCAI predicates in [`super::slsa`]/[`super::scai`]).
+
+use super::common::IN_TOTO_STATEMENT_TYPE;
+use super::Subject;
+use serde::{Deserialize, Serialize};
+
+/// An in-toto Statement whose predicate type this SDK doesn't recognize.
+/// `predicate` is carried as a raw [`serde_json::Value`] rather than erroring,
+/// so Makoto remains a superset consumer of the broader in-toto ecosystem.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RawStatement {
+    /// in-toto Statement type identifier.
+    #[serde(rename = "_type")]
+    pub statement_type: String,
+
+    /// The artifact(s) this statement describes.
+    pub subject: Vec<Subject>,
+
+    /// Predicate type identifier.
+    pub predicate_type: String,
+
+    /// The predicate, unparsed.
+    pub predicate: serde_json::Value,
+}
+
+impl RawStatement {
+    /// Validate only the envelope fields common to every in-toto Statement,
+    /// since the predicate's own structure is unknown and left unvalidated.
+    pub fn validate_envelope(&self) -> crate::error::Result<()> {
+        if self.statement_type != IN_TOTO_STATEMENT_TYPE {
+            return Err(crate::error::MakotoError::InvalidAttestation(format!(
+                "Invalid statement type: expected {}, got {}",
+                IN_TOTO_STATEMENT_TYPE, self.statement_type
+            )));
+        }
+
+        if self.subject.is_empty() {
+            return Err(crate::error::MakotoError::MissingField(
+                "subject".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Digest;
+
+    #[test]
+    fn test_raw_statement_validate_envelope_passes() {
+        let statement = RawStatement {
+            statement_type: IN_TOTO_STATEMENT_TYPE.to_string(),
+            subject: vec![Subject::new("dataset:test", Digest::new("a".repeat(64)))],
+            predicate_type: "https://example.com/custom-predicate/v1".to_string(),
+            predicate: serde_json::json!({"anything": "goes"}),
+        };
+        assert!(statement.validate_envelope().is_ok());
+    }
+
+    #[test]
+    fn test_raw_statement_validate_envelope_rejects_empty_subject() {
+        let statement = RawStatement {
+            statement_type: IN_TOTO_STATEMENT_TYPE.to_string(),
+            subject: vec![],
+            predicate_type: "https://example.com/custom-predicate/v1".to_string(),
+            predicate: serde_json::Value::Null,
+        };
+        assert!(statement.validate_envelope().is_err());
+    }
+}
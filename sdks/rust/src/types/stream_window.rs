@@ -259,6 +259,11 @@ pub struct WindowDescriptor {
     /// Maximum allowed lateness.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_lateness: Option<String>,
+
+    /// Log offsets the window covers, for gap detection against adjacent
+    /// windows via [`validate_position_contiguity`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<PositionDescriptor>,
 }
 
 impl WindowDescriptor {
@@ -271,6 +276,7 @@ impl WindowDescriptor {
             alignment: None,
             watermark: None,
             allowed_lateness: None,
+            position: None,
         }
     }
 
@@ -283,6 +289,7 @@ impl WindowDescriptor {
             alignment: None,
             watermark: None,
             allowed_lateness: None,
+            position: None,
         }
     }
 
@@ -295,6 +302,7 @@ impl WindowDescriptor {
             alignment: None,
             watermark: None,
             allowed_lateness: None,
+            position: None,
         }
     }
 
@@ -315,6 +323,109 @@ impl WindowDescriptor {
         self.allowed_lateness = Some(lateness.into());
         self
     }
+
+    /// Set the log offsets this window covers.
+    pub fn with_position(mut self, position: PositionDescriptor) -> Self {
+        self.position = Some(position);
+        self
+    }
+}
+
+/// Inclusive-start/exclusive-end log offsets a window covers for one
+/// partition, borrowed from the commit/prepare log-position model of
+/// event-sourced stores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionOffsets {
+    /// Inclusive offset of the first record the window covers.
+    pub start: u64,
+    /// Exclusive offset one past the last record the window covers.
+    pub end: u64,
+}
+
+impl PartitionOffsets {
+    /// Create a new offset range.
+    pub fn new(start: u64, end: u64) -> Self {
+        Self { start, end }
+    }
+}
+
+/// Per-partition (and optionally global) log position a window covers,
+/// turning a set of window attestations into a verifiable, contiguous
+/// ledger of a stream rather than independent snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionDescriptor {
+    /// Offsets per partition id, keyed the same as
+    /// `StreamDescriptor.partitions`.
+    pub partitions: HashMap<String, PartitionOffsets>,
+
+    /// Global log position, for streams with a single monotonic offset
+    /// rather than per-partition ones.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_position: Option<u64>,
+}
+
+impl PositionDescriptor {
+    /// Create a descriptor from per-partition offsets.
+    pub fn new(partitions: HashMap<String, PartitionOffsets>) -> Self {
+        Self {
+            partitions,
+            log_position: None,
+        }
+    }
+
+    /// Set the global log position.
+    pub fn with_log_position(mut self, log_position: u64) -> Self {
+        self.log_position = Some(log_position);
+        self
+    }
+}
+
+/// Confirm that `earlier`'s end offset equals `later`'s start offset for
+/// every partition appearing in either window, so missing or
+/// double-counted records between two consecutive attested windows of the
+/// same stream are detectable without re-reading the source.
+///
+/// Returns [`crate::error::MakotoError::PositionGapError`] enumerating
+/// every partition whose offsets don't connect, rather than stopping at
+/// the first.
+pub fn validate_position_contiguity(
+    earlier: &PositionDescriptor,
+    later: &PositionDescriptor,
+) -> crate::error::Result<()> {
+    let mut partitions: Vec<&String> = earlier
+        .partitions
+        .keys()
+        .chain(later.partitions.keys())
+        .collect();
+    partitions.sort();
+    partitions.dedup();
+
+    let mut issues = Vec::new();
+    for partition in partitions {
+        match (earlier.partitions.get(partition), later.partitions.get(partition)) {
+            (Some(e), Some(l)) if e.end == l.start => {}
+            (Some(e), Some(l)) => issues.push(format!(
+                "partition '{partition}': earlier window ends at {} but later window starts at {} \
+                 (gap or overlap)",
+                e.end, l.start
+            )),
+            (None, Some(_)) => issues.push(format!(
+                "partition '{partition}' appears in the later window but not the earlier one"
+            )),
+            (Some(_), None) => issues.push(format!(
+                "partition '{partition}' appears in the earlier window but not the later one"
+            )),
+            (None, None) => unreachable!("partition came from one of the two maps"),
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(crate::error::MakotoError::PositionGapError(issues))
+    }
 }
 
 /// Cryptographic integrity information for the window.
@@ -345,6 +456,30 @@ impl IntegrityDescriptor {
     }
 }
 
+/// Which tree structure a [`MerkleTreeDescriptor::root`] was built with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MerkleTreeKind {
+    /// A full [`crate::hash::MerkleTree`] rebuilt over every record in the
+    /// window.
+    #[serde(rename = "binary-sha256")]
+    BinarySha256,
+    /// A [`crate::hash::MerkleMountainRange`], appended to incrementally
+    /// across windows — lets [`ChainDescriptor::consistency_proof`]
+    /// cryptographically prove a new window's root extends the prior
+    /// window's, instead of trusting the chain pointer alone.
+    #[serde(rename = "mmr-sha256")]
+    MmrSha256,
+}
+
+impl Default for MerkleTreeKind {
+    /// Existing windows predate this field; they were always built as a
+    /// full tree.
+    fn default() -> Self {
+        Self::BinarySha256
+    }
+}
+
 /// Merkle tree parameters for window records.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -365,6 +500,11 @@ pub struct MerkleTreeDescriptor {
 
     /// Root hash of the Merkle tree.
     pub root: String,
+
+    /// Tree structure `root` was built with. Absent on attestations from
+    /// before this field existed, which were always [`MerkleTreeKind::BinarySha256`].
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub kind: Option<MerkleTreeKind>,
 }
 
 impl MerkleTreeDescriptor {
@@ -376,6 +516,7 @@ impl MerkleTreeDescriptor {
             leaf_count,
             tree_height: None,
             root: root.into(),
+            kind: None,
         }
     }
 
@@ -390,6 +531,18 @@ impl MerkleTreeDescriptor {
         self.leaf_hash_algorithm = Some(algorithm);
         self
     }
+
+    /// Set the tree structure kind.
+    pub fn with_kind(mut self, kind: MerkleTreeKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// The effective tree kind, defaulting to [`MerkleTreeKind::BinarySha256`]
+    /// for attestations that predate this field.
+    pub fn kind(&self) -> MerkleTreeKind {
+        self.kind.unwrap_or_default()
+    }
 }
 
 /// Hash chain linking windows for tamper-evident sequencing.
@@ -411,6 +564,15 @@ pub struct ChainDescriptor {
     /// ID of the first window in the chain.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub genesis_window_id: Option<String>,
+
+    /// Proof that this window's [`MerkleTreeDescriptor::root`] genuinely
+    /// extends `previous_merkle_root` as an [`crate::hash::MerkleMountainRange`]
+    /// — only meaningful when both windows' [`MerkleTreeDescriptor::kind`]
+    /// is [`MerkleTreeKind::MmrSha256`]. Checked by
+    /// [`crate::verification::verify_stream_window_structure`] instead of
+    /// trusting `previous_merkle_root` as a bare pointer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consistency_proof: Option<crate::hash::MmrConsistencyProofHex>,
 }
 
 impl ChainDescriptor {
@@ -421,6 +583,7 @@ impl ChainDescriptor {
             previous_merkle_root: None,
             chain_length: Some(1),
             genesis_window_id: Some(genesis_window_id.into()),
+            consistency_proof: None,
         }
     }
 
@@ -435,8 +598,16 @@ impl ChainDescriptor {
             previous_merkle_root: Some(previous_merkle_root.into()),
             chain_length: Some(chain_length),
             genesis_window_id: None,
+            consistency_proof: None,
         }
     }
+
+    /// Attach an MMR consistency proof linking this window back to the
+    /// previous one.
+    pub fn with_consistency_proof(mut self, proof: crate::hash::MmrConsistencyProofHex) -> Self {
+        self.consistency_proof = Some(proof);
+        self
+    }
 }
 
 /// Aggregate values for quick verification.
@@ -498,9 +669,148 @@ impl CollectorDescriptor {
     }
 }
 
-/// Operational metadata about window processing.
+/// The exact resumption point a collector reached: the last window it
+/// successfully attested, the per-partition offsets it had consumed, and
+/// its watermark.
+///
+/// A long-running collector persists this (via [`CollectorCursor::serialize`])
+/// so it can crash and restart while continuing the hash chain from the
+/// correct position — [`CollectorCursor::advance`] folds in each newly
+/// attested window, and the stored `last_merkle_root`/`last_window_id`
+/// become the next window's `ChainDescriptor::previous_merkle_root`/
+/// `previous_window_id`. [`CollectorCursor::verify_resumed_from`] lets a
+/// downstream verifier confirm a newly produced window was actually built
+/// from the cursor the collector claimed to resume from, closing the gap
+/// where a restarted collector could silently skip or replay records.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct CollectorCursor {
+    /// Subject name of the last window successfully attested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_window_id: Option<String>,
+
+    /// Merkle root of the last window successfully attested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_merkle_root: Option<String>,
+
+    /// Chain length of the last window successfully attested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_length: Option<u64>,
+
+    /// Per-partition offsets consumed so far.
+    pub partitions: HashMap<String, PartitionOffsets>,
+
+    /// Watermark timestamp the collector had reached.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watermark: Option<DateTime<Utc>>,
+}
+
+impl CollectorCursor {
+    /// Create a fresh cursor for a collector that hasn't attested anything
+    /// yet.
+    pub fn new() -> Self {
+        Self {
+            last_window_id: None,
+            last_merkle_root: None,
+            chain_length: None,
+            partitions: HashMap::new(),
+            watermark: None,
+        }
+    }
+
+    /// Serialize to JSON for persisted, resumable storage.
+    pub fn serialize(&self) -> crate::error::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Deserialize a cursor previously produced by [`CollectorCursor::serialize`].
+    pub fn deserialize(json: &str) -> crate::error::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Advance the cursor past a newly, successfully attested window:
+    /// records its subject name, Merkle root, and chain length, and merges
+    /// in its per-partition offsets and watermark.
+    pub fn advance(&mut self, window: &StreamWindowAttestation) {
+        if let Some(subject) = window.subject.first() {
+            self.last_window_id = Some(subject.name.clone());
+        }
+        self.last_merkle_root = Some(window.predicate.integrity.merkle_tree.root.clone());
+        if let Some(chain) = &window.predicate.integrity.chain {
+            self.chain_length = chain.chain_length;
+        }
+        if let Some(position) = &window.predicate.window.position {
+            for (partition, offsets) in &position.partitions {
+                self.partitions.insert(partition.clone(), *offsets);
+            }
+        }
+        if let Some(watermark) = window.predicate.window.watermark {
+            self.watermark = Some(watermark);
+        }
+    }
+
+    /// Confirm that `window` was actually built by resuming from this
+    /// cursor: its `ChainDescriptor.previous_window_id`/`previous_merkle_root`
+    /// match this cursor's last attested window, and for every partition
+    /// this cursor tracked, the window's start offset picks up exactly
+    /// where the cursor left off.
+    ///
+    /// Returns [`crate::error::MakotoError::PositionGapError`] enumerating
+    /// every mismatch rather than stopping at the first.
+    pub fn verify_resumed_from(&self, window: &StreamWindowAttestation) -> crate::error::Result<()> {
+        let mut issues = Vec::new();
+
+        match &window.predicate.integrity.chain {
+            Some(chain) => {
+                if chain.previous_window_id != self.last_window_id {
+                    issues.push(format!(
+                        "window's previous_window_id {:?} does not match cursor's last_window_id {:?}",
+                        chain.previous_window_id, self.last_window_id
+                    ));
+                }
+                if chain.previous_merkle_root != self.last_merkle_root {
+                    issues.push(format!(
+                        "window's previous_merkle_root {:?} does not match cursor's last_merkle_root {:?}",
+                        chain.previous_merkle_root, self.last_merkle_root
+                    ));
+                }
+            }
+            None if self.last_window_id.is_some() => issues.push(
+                "window has no ChainDescriptor but the cursor expected a resumed chain".to_string(),
+            ),
+            None => {}
+        }
+
+        if let Some(position) = &window.predicate.window.position {
+            for (partition, cursor_offsets) in &self.partitions {
+                if let Some(window_offsets) = position.partitions.get(partition) {
+                    if window_offsets.start != cursor_offsets.end {
+                        issues.push(format!(
+                            "partition '{partition}': cursor ends at {} but window starts at {}",
+                            cursor_offsets.end, window_offsets.start
+                        ));
+                    }
+                }
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::error::MakotoError::PositionGapError(issues))
+        }
+    }
+}
+
+impl Default for CollectorCursor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Operational metadata about window processing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct WindowMetadata {
     /// Processing latency (ISO 8601 duration).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -519,16 +829,6 @@ pub struct WindowMetadata {
     pub backpressure_events: Option<u64>,
 }
 
-impl Default for WindowMetadata {
-    fn default() -> Self {
-        Self {
-            processing_latency: None,
-            late_records: None,
-            dropped_records: None,
-            backpressure_events: None,
-        }
-    }
-}
 
 /// Verification information for individual records.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -600,4 +900,155 @@ mod tests {
         assert_eq!(linked.chain_length, Some(2));
         assert!(linked.previous_window_id.is_some());
     }
+
+    fn position(offsets: &[(&str, u64, u64)]) -> PositionDescriptor {
+        PositionDescriptor::new(
+            offsets
+                .iter()
+                .map(|(partition, start, end)| {
+                    (partition.to_string(), PartitionOffsets::new(*start, *end))
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_validate_position_contiguity_passes_for_contiguous_windows() {
+        let earlier = position(&[("p0", 0, 100), ("p1", 0, 50)]);
+        let later = position(&[("p0", 100, 200), ("p1", 50, 75)]);
+
+        assert!(validate_position_contiguity(&earlier, &later).is_ok());
+    }
+
+    #[test]
+    fn test_validate_position_contiguity_flags_gap() {
+        let earlier = position(&[("p0", 0, 100)]);
+        let later = position(&[("p0", 105, 200)]);
+
+        let err = validate_position_contiguity(&earlier, &later).unwrap_err();
+        match err {
+            crate::error::MakotoError::PositionGapError(issues) => {
+                assert_eq!(issues.len(), 1);
+                assert!(issues[0].contains("p0"));
+            }
+            other => panic!("expected PositionGapError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_position_contiguity_flags_missing_partition() {
+        let earlier = position(&[("p0", 0, 100), ("p1", 0, 50)]);
+        let later = position(&[("p0", 100, 200)]);
+
+        let err = validate_position_contiguity(&earlier, &later).unwrap_err();
+        match err {
+            crate::error::MakotoError::PositionGapError(issues) => {
+                assert!(issues.iter().any(|i| i.contains("p1")));
+            }
+            other => panic!("expected PositionGapError, got {other:?}"),
+        }
+    }
+
+    fn window_with_position(
+        name: &str,
+        root: &str,
+        chain: Option<ChainDescriptor>,
+        offsets: &[(&str, u64, u64)],
+    ) -> StreamWindowAttestation {
+        let merkle = MerkleTreeDescriptor::new(HashAlgorithm::Sha256, 10, root);
+        let mut integrity = IntegrityDescriptor::new(merkle);
+        if let Some(chain) = chain {
+            integrity = integrity.with_chain(chain);
+        }
+
+        StreamWindowAttestation::builder()
+            .subject(Subject::new(name, Digest::new("b".repeat(64))))
+            .stream(StreamDescriptor::new("iot_sensors"))
+            .window(WindowDescriptor::tumbling("PT1M").with_position(position(offsets)))
+            .integrity(integrity)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_collector_cursor_advances_and_serializes() {
+        let mut cursor = CollectorCursor::new();
+        assert!(cursor.last_window_id.is_none());
+
+        let window = window_with_position("window_1", &"a".repeat(64), None, &[("p0", 0, 100)]);
+        cursor.advance(&window);
+
+        assert_eq!(cursor.last_window_id, Some("window_1".to_string()));
+        assert_eq!(cursor.last_merkle_root, Some("a".repeat(64)));
+        assert_eq!(cursor.partitions.get("p0"), Some(&PartitionOffsets::new(0, 100)));
+
+        let json = cursor.serialize().unwrap();
+        let round_tripped = CollectorCursor::deserialize(&json).unwrap();
+        assert_eq!(round_tripped, cursor);
+    }
+
+    #[test]
+    fn test_collector_cursor_verifies_correctly_resumed_window() {
+        let mut cursor = CollectorCursor::new();
+        cursor.advance(&window_with_position(
+            "window_1",
+            &"a".repeat(64),
+            None,
+            &[("p0", 0, 100)],
+        ));
+
+        let mut chain = ChainDescriptor::linked("window_1", "a".repeat(64), 2);
+        chain.genesis_window_id = Some("window_1".to_string());
+        let resumed = window_with_position("window_2", &"b".repeat(64), Some(chain), &[("p0", 100, 200)]);
+
+        assert!(cursor.verify_resumed_from(&resumed).is_ok());
+    }
+
+    #[test]
+    fn test_collector_cursor_flags_skipped_records_after_restart() {
+        let mut cursor = CollectorCursor::new();
+        cursor.advance(&window_with_position(
+            "window_1",
+            &"a".repeat(64),
+            None,
+            &[("p0", 0, 100)],
+        ));
+
+        // Collector restarted and silently skipped ahead instead of
+        // resuming exactly at offset 100.
+        let mut chain = ChainDescriptor::linked("window_1", "a".repeat(64), 2);
+        chain.genesis_window_id = Some("window_1".to_string());
+        let resumed = window_with_position("window_2", &"b".repeat(64), Some(chain), &[("p0", 150, 200)]);
+
+        let err = cursor.verify_resumed_from(&resumed).unwrap_err();
+        match err {
+            crate::error::MakotoError::PositionGapError(issues) => {
+                assert!(issues.iter().any(|i| i.contains("p0")));
+            }
+            other => panic!("expected PositionGapError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_collector_cursor_flags_wrong_chain_link() {
+        let mut cursor = CollectorCursor::new();
+        cursor.advance(&window_with_position(
+            "window_1",
+            &"a".repeat(64),
+            None,
+            &[("p0", 0, 100)],
+        ));
+
+        let mut chain = ChainDescriptor::linked("some_other_window", "c".repeat(64), 2);
+        chain.genesis_window_id = Some("window_1".to_string());
+        let resumed = window_with_position("window_2", &"b".repeat(64), Some(chain), &[("p0", 100, 200)]);
+
+        let err = cursor.verify_resumed_from(&resumed).unwrap_err();
+        match err {
+            crate::error::MakotoError::PositionGapError(issues) => {
+                assert_eq!(issues.len(), 2);
+            }
+            other => panic!("expected PositionGapError, got {other:?}"),
+        }
+    }
 }
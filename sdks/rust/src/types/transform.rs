@@ -1,6 +1,7 @@
 //! Transform attestation types for documenting data transformations.
 
 use super::common::*;
+use crate::authz::UcanChain;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -62,6 +63,34 @@ impl TransformAttestation {
 
         Ok(())
     }
+
+    /// Validate the UCAN-style authorization chain attached to this
+    /// attestation, if any, against a set of trusted root DIDs, as of
+    /// `reference_time`.
+    ///
+    /// Returns an error if `authorization` is absent, or if it fails any of
+    /// the checks in [`crate::authz::validate_authorization`] (including
+    /// that every link in the chain has not expired as of `reference_time`).
+    pub fn validate_authorization(
+        &self,
+        trusted_roots: &[crate::authz::Did],
+        verifier: &dyn crate::authz::DidVerifier,
+        reference_time: DateTime<Utc>,
+    ) -> crate::error::Result<()> {
+        let chain = self.predicate.authorization.as_ref().ok_or_else(|| {
+            crate::error::MakotoError::MissingField("predicate.authorization".to_string())
+        })?;
+
+        crate::authz::validate_authorization(
+            chain,
+            &self.predicate,
+            &self.subject,
+            &self.predicate.executor.id,
+            trusted_roots,
+            verifier,
+            reference_time,
+        )
+    }
 }
 
 /// Builder for creating transform attestations.
@@ -73,6 +102,7 @@ pub struct TransformAttestationBuilder {
     executor: Option<Executor>,
     metadata: Option<ExecutionMetadata>,
     verification: Option<VerificationInfo>,
+    authorization: Option<UcanChain>,
 }
 
 impl TransformAttestationBuilder {
@@ -112,6 +142,44 @@ impl TransformAttestationBuilder {
         self
     }
 
+    /// Set the UCAN-style delegation chain authorizing this transform.
+    pub fn authorization(mut self, authorization: UcanChain) -> Self {
+        self.authorization = Some(authorization);
+        self
+    }
+
+    /// Re-hash every input added so far via `fetcher` and record the result
+    /// in `VerificationInfo.input_hash_verified` (true only if every input
+    /// that could be fetched verified; inputs that can't be fetched are
+    /// skipped rather than treated as failures).
+    pub fn verify_inputs(mut self, fetcher: &dyn crate::verify::ArtifactFetcher) -> Self {
+        use crate::verify::DigestVerifier;
+
+        let verifier = DigestVerifier::new();
+        let mut all_verified = true;
+        let mut any_checked = false;
+
+        for input in &self.inputs {
+            let Ok(reader) = fetcher.fetch(&input.name) else {
+                continue;
+            };
+
+            any_checked = true;
+            match verifier.verify_digest(&input.digest, HashAlgorithm::Sha256, reader) {
+                Ok(check) if check.matches => {}
+                _ => all_verified = false,
+            }
+        }
+
+        if any_checked {
+            let mut verification = self.verification.unwrap_or_default();
+            verification.input_hash_verified = Some(all_verified);
+            self.verification = Some(verification);
+        }
+
+        self
+    }
+
     /// Build the attestation.
     pub fn build(self) -> crate::error::Result<TransformAttestation> {
         let transform = self
@@ -143,6 +211,7 @@ impl TransformAttestationBuilder {
                 executor,
                 metadata: self.metadata,
                 verification: self.verification,
+                authorization: self.authorization,
             },
         })
     }
@@ -167,6 +236,11 @@ pub struct TransformPredicate {
     /// Verification information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verification: Option<VerificationInfo>,
+
+    /// UCAN-style delegation chain proving the executor was authorized to
+    /// attest transforms over the datasets involved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<UcanChain>,
 }
 
 /// Reference to an input data artifact.
@@ -373,10 +447,16 @@ impl Executor {
         self.isolation = Some(isolation);
         self
     }
+
+    /// Set version information (e.g. image digest, runtime version).
+    pub fn with_version(mut self, version: HashMap<String, String>) -> Self {
+        self.version = Some(version);
+        self
+    }
 }
 
 /// Metadata about the transformation execution.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExecutionMetadata {
     /// Unique identifier for this execution.
@@ -420,25 +500,8 @@ pub struct ExecutionMetadata {
     pub bytes_output: Option<u64>,
 }
 
-impl Default for ExecutionMetadata {
-    fn default() -> Self {
-        Self {
-            invocation_id: None,
-            started_on: None,
-            finished_on: None,
-            duration_seconds: None,
-            records_input: None,
-            records_output: None,
-            records_dropped: None,
-            records_modified: None,
-            bytes_input: None,
-            bytes_output: None,
-        }
-    }
-}
-
 /// Information about verification performed during transformation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VerificationInfo {
     /// Whether input hashes were verified.
@@ -454,16 +517,6 @@ pub struct VerificationInfo {
     pub output_reproducible: Option<bool>,
 }
 
-impl Default for VerificationInfo {
-    fn default() -> Self {
-        Self {
-            input_hash_verified: None,
-            transform_deterministic: None,
-            output_reproducible: None,
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
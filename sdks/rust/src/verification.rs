@@ -2,12 +2,24 @@
 //!
 //! Provides hash verification, attestation validation, and chain verification.
 
+use std::collections::HashSet;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use chrono::{DateTime, Utc};
+
 use crate::error::{MakotoError, Result};
-use crate::hash::sha256_hex;
-use crate::signing::{MakotoVerifier, SignedAttestation};
+use crate::hash::{self, sha256_hex, HashMode};
+use crate::signing::{
+    InclusionProof, LogCheckpoint, MakotoKeyring, MakotoVerifier, SignedAttestation,
+    TransparencyLogEntry, VerifierSet, SIGNATURE_ALGORITHM,
+};
+use crate::trust::TrustRoot;
 use crate::types::{
-    Digest, MakotoLevel, OriginAttestation, StreamWindowAttestation, TransformAttestation,
-    ORIGIN_PREDICATE_TYPE, STREAM_WINDOW_PREDICATE_TYPE, TRANSFORM_PREDICATE_TYPE,
+    Digest, HashAlgorithm, MakotoLevel, OriginAttestation, RawStatement, ScaiAttestation,
+    SlsaProvenanceV02Attestation, SlsaProvenanceV1Attestation, StreamWindowAttestation,
+    TransformAttestation, ORIGIN_PREDICATE_TYPE, SCAI_PREDICATE_TYPE,
+    SLSA_PROVENANCE_V02_PREDICATE_TYPE, SLSA_PROVENANCE_V1_PREDICATE_TYPE,
+    STREAM_WINDOW_PREDICATE_TYPE, TRANSFORM_PREDICATE_TYPE,
 };
 
 /// Result of attestation verification.
@@ -147,6 +159,38 @@ pub fn verify_transform_structure(attestation: &TransformAttestation) -> Verific
         .with_message("Transform attestation structure is valid")
 }
 
+/// Verify a transform attestation's structure, then (if it's present) that
+/// `predicate.authorization` is a valid, unexpired, properly-attenuated
+/// delegation chain rooted at one of `trusted_roots` and ending at the
+/// attestation's own executor — see [`crate::authz::validate_authorization`].
+///
+/// [`verify_transform_structure`] alone only checks structural
+/// completeness: a `predicate.inputs[].attestation_ref` is just a URL, with
+/// no proof the executor was ever authorized to transform that input. This
+/// adds that proof when `trusted_roots` names the dataset's origin
+/// attester (or a root that delegates to it). An attestation with no
+/// `authorization` chain attached fails closed rather than passing
+/// silently — callers that don't require delegation should keep using
+/// [`verify_transform_structure`] directly.
+pub fn verify_transform_structure_with_authorization(
+    attestation: &TransformAttestation,
+    trusted_roots: &[crate::authz::Did],
+    verifier: &dyn crate::authz::DidVerifier,
+    reference_time: chrono::DateTime<Utc>,
+) -> VerificationResult {
+    let structure = verify_transform_structure(attestation);
+    if !structure.valid {
+        return structure;
+    }
+
+    match attestation.validate_authorization(trusted_roots, verifier, reference_time) {
+        Ok(()) => VerificationResult::pass(MakotoLevel::L1).with_message(
+            "Transform attestation structure is valid and its delegation chain is valid",
+        ),
+        Err(e) => VerificationResult::fail(format!("Authorization chain invalid: {}", e)),
+    }
+}
+
 /// Verify a stream window attestation structure (L1 check).
 pub fn verify_stream_window_structure(
     attestation: &StreamWindowAttestation,
@@ -183,6 +227,49 @@ pub fn verify_stream_window_structure(
             if prev_id.is_empty() {
                 return VerificationResult::fail("Previous window ID is empty");
             }
+
+            // For MMR-backed windows, a bare root pointer is only a claim;
+            // if a consistency proof was attached, cryptographically check
+            // that this window's tree genuinely extends the prior one
+            // instead of trusting `previous_merkle_root` on its own.
+            if merkle.kind() == crate::types::stream_window::MerkleTreeKind::MmrSha256 {
+                if let Some(proof_hex) = &chain.consistency_proof {
+                    let proof = match proof_hex.to_proof() {
+                        Ok(p) => p,
+                        Err(e) => {
+                            return VerificationResult::fail(format!(
+                                "Malformed MMR consistency proof: {}",
+                                e
+                            ));
+                        }
+                    };
+
+                    let old_root = match hash::hash_from_hex(prev_root) {
+                        Ok(r) => r,
+                        Err(e) => {
+                            return VerificationResult::fail(format!(
+                                "Invalid previous Merkle root hex: {}",
+                                e
+                            ));
+                        }
+                    };
+                    let new_root = match hash::hash_from_hex(&merkle.root) {
+                        Ok(r) => r,
+                        Err(e) => {
+                            return VerificationResult::fail(format!(
+                                "Invalid Merkle root hex: {}",
+                                e
+                            ));
+                        }
+                    };
+
+                    if !hash::verify_mmr_consistency(&old_root, &new_root, &proof) {
+                        return VerificationResult::fail(
+                            "MMR consistency proof does not confirm this window extends the prior one",
+                        );
+                    }
+                }
+            }
         }
     }
 
@@ -190,6 +277,68 @@ pub fn verify_stream_window_structure(
         .with_message("Stream window attestation structure is valid")
 }
 
+/// Verify that `windows` forms an unbroken chain: for each window after the
+/// first, its `chain.previous_merkle_root`/`previous_window_id` must match
+/// the prior window's attested root/subject name.
+///
+/// `verify_stream_window_structure` only checks those fields are
+/// well-formed on a single window; this walks a whole sequence and fails on
+/// the first broken link, naming the index and the expected-vs-actual root
+/// so an operator can pinpoint exactly where a stream was truncated or
+/// spliced. For a structured report of every break instead of just the
+/// first, see [`crate::chain::verify_chain`].
+pub fn verify_window_chain(windows: &[StreamWindowAttestation]) -> VerificationResult {
+    if windows.is_empty() {
+        return VerificationResult::pass(MakotoLevel::L1)
+            .with_message("Empty window sequence trivially forms an unbroken chain");
+    }
+
+    let mut result = VerificationResult::pass(MakotoLevel::L1);
+
+    for i in 1..windows.len() {
+        let prior = &windows[i - 1];
+        let current = &windows[i];
+
+        let prior_root = &prior.predicate.integrity.merkle_tree.root;
+        let prior_id = prior.subject.first().map(|s| s.name.as_str()).unwrap_or("");
+
+        let chain = match &current.predicate.integrity.chain {
+            Some(chain) => chain,
+            None => {
+                return VerificationResult::fail(format!(
+                    "window {i} has no ChainDescriptor linking it to the prior window"
+                ));
+            }
+        };
+
+        match &chain.previous_merkle_root {
+            Some(root) if root == prior_root => {}
+            other => {
+                return VerificationResult::fail(format!(
+                    "window {i} previous_merkle_root {:?} does not match prior window's root {}",
+                    other, prior_root
+                ));
+            }
+        }
+
+        match &chain.previous_window_id {
+            Some(id) if id == prior_id => {}
+            other => {
+                return VerificationResult::fail(format!(
+                    "window {i} previous_window_id {:?} does not match prior window's id {:?}",
+                    other, prior_id
+                ));
+            }
+        }
+
+        result = result.with_message(format!(
+            "window {i} links correctly to prior window's root {prior_root}"
+        ));
+    }
+
+    result
+}
+
 /// Verify a signed attestation (L2 check).
 pub fn verify_signed_attestation<T>(
     signed: &SignedAttestation,
@@ -216,10 +365,488 @@ where
         .with_message(format!("Signature verified for key: {}", verifier.key_id()))
 }
 
+/// Verify an entry was included in a transparency log's Merkle tree, and
+/// that the log's checkpoint claiming that tree is itself signed by the
+/// log's key (L3 check).
+///
+/// Recomputes the root by folding `entry_hash` upward through
+/// `proof.audit_path` with RFC 6962 domain separation (`0x00` leaf prefix,
+/// `0x01` interior-node prefix) so hashes match standard transparency logs,
+/// then compares it against `checkpoint.root_hash` and verifies
+/// `checkpoint`'s own signature via `log_verifier`. The audit path's
+/// expected length varies with `(leaf_index, tree_size)` — it's only
+/// `ceil(log2(tree_size))` for a perfectly balanced tree — so the fold
+/// itself rejects a path with the wrong number of entries rather than
+/// this function checking a fixed length up front.
+pub fn verify_transparency_inclusion(
+    entry_hash: &[u8],
+    proof: &InclusionProof,
+    checkpoint: &LogCheckpoint,
+    log_verifier: &MakotoVerifier,
+) -> VerificationResult {
+    if proof.tree_size != checkpoint.tree_size {
+        return VerificationResult::fail(format!(
+            "inclusion proof tree size {} does not match checkpoint tree size {}",
+            proof.tree_size, checkpoint.tree_size
+        ));
+    }
+
+    if proof.leaf_index >= proof.tree_size {
+        return VerificationResult::fail(format!(
+            "leaf index {} is out of range for tree size {}",
+            proof.leaf_index, proof.tree_size
+        ));
+    }
+
+    let hasher = match hash::make_hasher(HashAlgorithm::Sha256, HashMode::Rfc6962) {
+        Ok(hasher) => hasher,
+        Err(e) => return VerificationResult::fail(format!("Hasher setup failed: {}", e)),
+    };
+
+    let mut siblings = Vec::with_capacity(proof.audit_path.len());
+    for sibling_hex in &proof.audit_path {
+        match hash::hash_from_hex(sibling_hex) {
+            Ok(s) => siblings.push(s),
+            Err(e) => return VerificationResult::fail(format!("Invalid audit path hash: {}", e)),
+        }
+    }
+
+    let leaf_hash = hasher.hash_leaf(entry_hash);
+    let current = match hash::fold_rfc6962_path(
+        leaf_hash,
+        proof.leaf_index,
+        proof.tree_size,
+        &siblings,
+        hasher.as_ref(),
+    ) {
+        Ok(root) => root,
+        Err(e) => return VerificationResult::fail(format!("Invalid audit path: {}", e)),
+    };
+
+    let expected_root = match hash::hash_from_hex(&checkpoint.root_hash) {
+        Ok(r) => r,
+        Err(e) => return VerificationResult::fail(format!("Invalid checkpoint root hash: {}", e)),
+    };
+
+    if current != expected_root {
+        return VerificationResult::fail(
+            "Recomputed root does not match the checkpoint's signed tree root",
+        );
+    }
+
+    match checkpoint.verify_signature(log_verifier) {
+        Ok(true) => {}
+        Ok(false) => return VerificationResult::fail("Checkpoint signature verification failed"),
+        Err(e) => return VerificationResult::fail(format!("Checkpoint signature error: {}", e)),
+    }
+
+    VerificationResult::pass(MakotoLevel::L3)
+        .with_message("Entry inclusion verified against a signed transparency log checkpoint")
+}
+
+/// Verify a [`TransparencyLogEntry`]'s inclusion proof against `leaf` (the
+/// exact bytes the log indexed this entry under) and `log_verifier` (the log
+/// operator's key) — a thin `Result<bool>` wrapper over
+/// [`verify_transparency_inclusion`] for callers that want a plain boolean
+/// rather than a full [`VerificationResult`]. Reuses the exact same RFC 6962
+/// folding and checkpoint-signature check, so the two can't drift apart.
+pub fn verify_inclusion(
+    entry: &TransparencyLogEntry,
+    leaf: &[u8],
+    log_verifier: &MakotoVerifier,
+) -> Result<bool> {
+    let result = verify_transparency_inclusion(leaf, &entry.proof, &entry.checkpoint, log_verifier);
+    Ok(result.valid)
+}
+
+/// Smallest number of tree levels needed to cover `n` leaves
+/// (`ceil(log2(n))`, with `ceil(log2(0)) = ceil(log2(1)) = 0`).
+fn ceil_log2(n: u64) -> u32 {
+    if n <= 1 {
+        0
+    } else {
+        64 - (n - 1).leading_zeros()
+    }
+}
+
+/// Verify that `record` is actually included in `attestation`'s attested
+/// Merkle tree, at `leaf_index`, given its `audit_path` (the ordered sibling
+/// hashes, hex-encoded, leaf to root).
+///
+/// `verify_stream_window_structure` only checks the window's Merkle root is
+/// well-formed; this lets a downstream consumer prove one specific streamed
+/// record was included in that window without re-downloading the whole
+/// thing. Hashes with RFC 6962 domain separation, matching the trees
+/// [`crate::merkle::build_window_tree`] produces.
+pub fn verify_record_in_window(
+    attestation: &StreamWindowAttestation,
+    record: &[u8],
+    leaf_index: usize,
+    audit_path: &[String],
+) -> VerificationResult {
+    let merkle = &attestation.predicate.integrity.merkle_tree;
+
+    if leaf_index as u64 >= merkle.leaf_count {
+        return VerificationResult::fail(format!(
+            "leaf index {} is out of range for leaf count {}",
+            leaf_index, merkle.leaf_count
+        ));
+    }
+
+    let expected_path_len = ceil_log2(merkle.leaf_count);
+    if audit_path.len() as u32 != expected_path_len {
+        return VerificationResult::fail(format!(
+            "audit path has {} entries, expected {} for a window with {} leaves",
+            audit_path.len(),
+            expected_path_len,
+            merkle.leaf_count
+        ));
+    }
+
+    let hasher = match hash::make_hasher(merkle.algorithm, HashMode::Rfc6962) {
+        Ok(hasher) => hasher,
+        Err(e) => return VerificationResult::fail(format!("Hasher setup failed: {}", e)),
+    };
+
+    let mut current = hasher.hash_leaf(record);
+    let mut index = leaf_index;
+    // One audit_path entry per tree level, same as crate::hash::MerkleTree::proof:
+    // a level with an odd node count emits a placeholder (equal to `current`,
+    // ignored here) for the lone trailing node rather than a real sibling, so
+    // `level_len` has to be tracked to tell a real sibling from an RFC 6962
+    // carried-up placeholder.
+    let mut level_len = merkle.leaf_count as usize;
+    for sibling_hex in audit_path {
+        let sibling_index = if index.is_multiple_of(2) { index + 1 } else { index - 1 };
+        if sibling_index < level_len {
+            let sibling = match hash::hash_from_hex(sibling_hex) {
+                Ok(s) => s,
+                Err(e) => return VerificationResult::fail(format!("Invalid audit path hash: {}", e)),
+            };
+            current = if index.is_multiple_of(2) {
+                hasher.hash_pair(&current, &sibling)
+            } else {
+                hasher.hash_pair(&sibling, &current)
+            };
+        }
+        index /= 2;
+        level_len = level_len.div_ceil(2);
+    }
+
+    let expected_root = match hash::hash_from_hex(&merkle.root) {
+        Ok(r) => r,
+        Err(e) => return VerificationResult::fail(format!("Invalid window Merkle root: {}", e)),
+    };
+
+    if current != expected_root {
+        return VerificationResult::fail(
+            "Recomputed root does not match the window's attested Merkle root",
+        );
+    }
+
+    VerificationResult::pass(MakotoLevel::L1)
+        .with_message("Record inclusion verified against the window's Merkle root")
+}
+
+/// Policy a [`SignedAttestation`] must satisfy beyond having a valid
+/// signature, checked by [`verify_signed_attestation_with_policy`].
+/// Borrows the shape of `jsonwebtoken`'s `Validation`: an algorithm
+/// allow-list, an issuer allow-list, an optional expected audience, and a
+/// leeway window applied to expiry/not-before checks.
+#[derive(Debug, Clone)]
+pub struct VerificationPolicy {
+    /// Signing algorithms accepted (e.g. [`crate::signing::SIGNATURE_ALGORITHM`]).
+    /// Must be non-empty — an empty allow-list is a hard configuration
+    /// error, never "accept anything", matching `jsonwebtoken`'s safer
+    /// default.
+    pub allowed_algorithms: HashSet<String>,
+    /// The attestation's claimed issuer must be one of these.
+    pub required_issuers: HashSet<String>,
+    /// Audience the attestation must be scoped to, if set.
+    pub expected_audience: Option<String>,
+    /// Clock skew tolerance (seconds) applied to `not_before`/`expires_at`.
+    pub leeway_secs: u64,
+}
+
+impl VerificationPolicy {
+    /// Create a policy with the given algorithm and issuer allow-lists and
+    /// no audience restriction or leeway.
+    pub fn new(allowed_algorithms: HashSet<String>, required_issuers: HashSet<String>) -> Self {
+        Self {
+            allowed_algorithms,
+            required_issuers,
+            expected_audience: None,
+            leeway_secs: 0,
+        }
+    }
+
+    /// Require a specific audience.
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.expected_audience = Some(audience.into());
+        self
+    }
+
+    /// Tolerate this many seconds of clock skew on expiry checks.
+    pub fn with_leeway_secs(mut self, leeway_secs: u64) -> Self {
+        self.leeway_secs = leeway_secs;
+        self
+    }
+}
+
+/// Verify a signed attestation against a [`VerificationPolicy`] (L2 check,
+/// hardened with issuer/audience/expiry/algorithm checks).
+///
+/// Beyond what [`verify_signed_attestation`] checks, this rejects envelopes
+/// signed with an algorithm outside `policy.allowed_algorithms`, requires
+/// `signed.claims` to be present with an issuer in `policy.required_issuers`,
+/// checks the audience if `policy.expected_audience` is set, and validates
+/// `not_before`/`expires_at` against `now ± policy.leeway_secs`. An empty
+/// `allowed_algorithms` is rejected outright rather than treated as "accept
+/// anything".
+pub fn verify_signed_attestation_with_policy<T>(
+    signed: &SignedAttestation,
+    verifier: &MakotoVerifier,
+    policy: &VerificationPolicy,
+) -> VerificationResult
+where
+    T: serde::de::DeserializeOwned,
+{
+    if policy.allowed_algorithms.is_empty() {
+        return VerificationResult::fail(
+            "VerificationPolicy.allowed_algorithms is empty; refusing to accept any algorithm",
+        );
+    }
+
+    if !policy.allowed_algorithms.contains(SIGNATURE_ALGORITHM) {
+        return VerificationResult::fail(format!(
+            "signing algorithm {} is not in the policy's allow-list",
+            SIGNATURE_ALGORITHM
+        ));
+    }
+
+    match signed.verify(verifier) {
+        Ok(true) => {}
+        Ok(false) => return VerificationResult::fail("Signature verification failed"),
+        Err(e) => return VerificationResult::fail(format!("Signature error: {}", e)),
+    }
+
+    let _payload: T = match signed.decode_payload() {
+        Ok(p) => p,
+        Err(e) => return VerificationResult::fail(format!("Payload decode error: {}", e)),
+    };
+
+    let claims = match &signed.claims {
+        Some(claims) => claims,
+        None => {
+            return VerificationResult::fail(
+                "attestation has no claims to check against the verification policy",
+            )
+        }
+    };
+
+    if !policy.required_issuers.contains(&claims.issuer) {
+        return VerificationResult::fail(format!(
+            "issuer '{}' is not in the policy's required issuers",
+            claims.issuer
+        ));
+    }
+
+    if let Some(expected) = &policy.expected_audience {
+        match &claims.audience {
+            Some(audience) if audience == expected => {}
+            other => {
+                return VerificationResult::fail(format!(
+                    "audience {:?} does not match expected audience '{}'",
+                    other, expected
+                ));
+            }
+        }
+    }
+
+    let now = Utc::now();
+    let leeway = chrono::Duration::seconds(policy.leeway_secs as i64);
+
+    if let Some(not_before) = claims.not_before {
+        if now + leeway < not_before {
+            return VerificationResult::fail(format!(
+                "attestation is not valid until {}",
+                not_before
+            ));
+        }
+    }
+
+    if let Some(expires_at) = claims.expires_at {
+        if now - leeway > expires_at {
+            return VerificationResult::fail(format!("attestation expired at {}", expires_at));
+        }
+    }
+
+    VerificationResult::pass(MakotoLevel::L2)
+        .with_message("Signed attestation satisfies verification policy")
+        .with_message(format!("Signature verified for key: {}", verifier.key_id()))
+}
+
+/// Verify a signed attestation against a [`VerifierSet`] rather than a
+/// single [`MakotoVerifier`] (L2 check), for deployments that rotate keys
+/// or mix algorithms.
+///
+/// For each signature on the envelope, looks up `sig.keyid` in `set` and,
+/// if found, checks that the signature's declared algorithm (or
+/// [`SIGNATURE_ALGORITHM`] if the envelope predates that field) matches
+/// what the set allows for that key before verifying the signature itself.
+/// Fails with a distinct message if no signature's `keyid` is in the set,
+/// and a distinct message if a matching key's declared algorithm is
+/// disallowed — both are reported rather than only the last error seen, so
+/// the caller can tell a key-rotation gap from an algorithm-downgrade
+/// attempt.
+pub fn verify_signed_attestation_multi<T>(
+    signed: &SignedAttestation,
+    set: &VerifierSet,
+) -> VerificationResult
+where
+    T: serde::de::DeserializeOwned,
+{
+    if signed.signatures.is_empty() {
+        return VerificationResult::fail("envelope has no signatures");
+    }
+
+    let mut errors: Vec<String> = Vec::new();
+
+    for sig in &signed.signatures {
+        let entry = match set.get(&sig.keyid) {
+            Some(entry) => entry,
+            None => {
+                errors.push(format!("no verifier in the set matches key id '{}'", sig.keyid));
+                continue;
+            }
+        };
+
+        let declared_algorithm = sig.algorithm.as_deref().unwrap_or(SIGNATURE_ALGORITHM);
+        if declared_algorithm != entry.algorithm {
+            errors.push(format!(
+                "key '{}' is only allowed with algorithm '{}', envelope declares '{}'",
+                sig.keyid, entry.algorithm, declared_algorithm
+            ));
+            continue;
+        }
+
+        match signed.verify(&entry.verifier) {
+            Ok(true) => {
+                let _payload: T = match signed.decode_payload() {
+                    Ok(p) => p,
+                    Err(e) => return VerificationResult::fail(format!("Payload decode error: {}", e)),
+                };
+
+                return VerificationResult::pass(MakotoLevel::L2)
+                    .with_message(format!("Signature verified for key: {}", sig.keyid))
+                    .with_message(format!("Algorithm: {}", declared_algorithm));
+            }
+            Ok(false) => {
+                errors.push(format!("signature verification failed for key '{}'", sig.keyid));
+            }
+            Err(e) => {
+                errors.push(format!("signature error for key '{}': {}", sig.keyid, e));
+            }
+        }
+    }
+
+    // Every signature was tried and none verified; report each one's failure.
+    VerificationResult::fail(errors.join("; "))
+}
+
+/// Verify a multi-signed envelope against a threshold policy: passes only
+/// if at least `min_signatures` *distinct* authorized keys in `keyset` each
+/// produced a valid signature over the envelope's shared pre-auth-encoded
+/// payload (L2 check).
+///
+/// Signatures with a `keyid` not in `keyset` are ignored rather than
+/// treated as failures — a real co-signed chain may carry signatures from
+/// parties outside a given verifier's trust set. Multiple signatures from
+/// the same key count once toward the threshold. Every keyid that
+/// contributed a valid signature is reported in the result's messages, so
+/// the caller can see which signers satisfied the policy.
+pub fn verify_threshold(
+    signed: &SignedAttestation,
+    keyset: &VerifierSet,
+    min_signatures: usize,
+) -> VerificationResult {
+    if signed.signatures.is_empty() {
+        return VerificationResult::fail("envelope has no signatures");
+    }
+
+    let mut satisfied: HashSet<String> = HashSet::new();
+    let mut notes = Vec::new();
+
+    for sig in &signed.signatures {
+        if satisfied.contains(&sig.keyid) {
+            continue;
+        }
+
+        let entry = match keyset.get(&sig.keyid) {
+            Some(entry) => entry,
+            None => continue,
+        };
+
+        let declared_algorithm = sig.algorithm.as_deref().unwrap_or(SIGNATURE_ALGORITHM);
+        if declared_algorithm != entry.algorithm {
+            notes.push(format!(
+                "key '{}' is only allowed with algorithm '{}', envelope declares '{}'; ignoring",
+                sig.keyid, entry.algorithm, declared_algorithm
+            ));
+            continue;
+        }
+
+        match signed.verify(&entry.verifier) {
+            Ok(true) => {
+                satisfied.insert(sig.keyid.clone());
+                notes.push(format!("signature verified for key: {}", sig.keyid));
+            }
+            Ok(false) => {
+                notes.push(format!("signature verification failed for key '{}'", sig.keyid));
+            }
+            Err(e) => {
+                notes.push(format!("signature error for key '{}': {}", sig.keyid, e));
+            }
+        }
+    }
+
+    let mut satisfied_keyids: Vec<String> = satisfied.into_iter().collect();
+    satisfied_keyids.sort();
+
+    if satisfied_keyids.len() >= min_signatures {
+        let mut result = VerificationResult::pass(MakotoLevel::L2).with_message(format!(
+            "threshold satisfied: {} of {} required distinct keys verified ({})",
+            satisfied_keyids.len(),
+            min_signatures,
+            satisfied_keyids.join(", ")
+        ));
+        for note in notes {
+            result = result.with_message(note);
+        }
+        result
+    } else {
+        let mut result = VerificationResult::fail(format!(
+            "threshold not met: only {} of {} required distinct keys verified ({})",
+            satisfied_keyids.len(),
+            min_signatures,
+            satisfied_keyids.join(", ")
+        ));
+        for note in notes {
+            result = result.with_warning(note);
+        }
+        result
+    }
+}
+
 /// Detect attestation type from JSON.
 pub fn detect_attestation_type(json: &str) -> Result<AttestationType> {
     let value: serde_json::Value = serde_json::from_str(json)?;
 
+    // Check if it's a MakotoBundle
+    if value.get("mediaType").is_some() && value.get("bundleVersion").is_some() {
+        return Ok(AttestationType::Bundle);
+    }
+
     // Check if it's a signed envelope
     if value.get("payloadType").is_some() && value.get("signatures").is_some() {
         return Ok(AttestationType::Signed);
@@ -227,12 +854,18 @@ pub fn detect_attestation_type(json: &str) -> Result<AttestationType> {
 
     // Check predicate type
     if let Some(pred_type) = value.get("predicateType").and_then(|v| v.as_str()) {
-        match pred_type {
-            ORIGIN_PREDICATE_TYPE => return Ok(AttestationType::Origin),
-            TRANSFORM_PREDICATE_TYPE => return Ok(AttestationType::Transform),
-            STREAM_WINDOW_PREDICATE_TYPE => return Ok(AttestationType::StreamWindow),
-            _ => {}
-        }
+        return Ok(match pred_type {
+            ORIGIN_PREDICATE_TYPE => AttestationType::Origin,
+            TRANSFORM_PREDICATE_TYPE => AttestationType::Transform,
+            STREAM_WINDOW_PREDICATE_TYPE => AttestationType::StreamWindow,
+            SLSA_PROVENANCE_V02_PREDICATE_TYPE => AttestationType::SlsaProvenanceV02,
+            SLSA_PROVENANCE_V1_PREDICATE_TYPE => AttestationType::SlsaProvenanceV1,
+            SCAI_PREDICATE_TYPE => AttestationType::Scai,
+            // Not a predicate type this SDK models — fall back to a raw
+            // in-toto Statement rather than erroring, so Makoto remains a
+            // superset consumer of the broader in-toto ecosystem.
+            _ => AttestationType::Unrecognized,
+        });
     }
 
     // Check for DBOM
@@ -253,6 +886,14 @@ pub enum AttestationType {
     StreamWindow,
     Dbom,
     Signed,
+    Bundle,
+    SlsaProvenanceV02,
+    SlsaProvenanceV1,
+    Scai,
+    /// A `predicateType` this SDK doesn't model; verified at the envelope
+    /// level only, with the predicate carried as a raw
+    /// [`crate::types::RawStatement`].
+    Unrecognized,
 }
 
 impl std::fmt::Display for AttestationType {
@@ -263,6 +904,11 @@ impl std::fmt::Display for AttestationType {
             AttestationType::StreamWindow => write!(f, "stream-window"),
             AttestationType::Dbom => write!(f, "dbom"),
             AttestationType::Signed => write!(f, "signed"),
+            AttestationType::Bundle => write!(f, "bundle"),
+            AttestationType::SlsaProvenanceV02 => write!(f, "slsa-provenance-v0.2"),
+            AttestationType::SlsaProvenanceV1 => write!(f, "slsa-provenance-v1"),
+            AttestationType::Scai => write!(f, "scai"),
+            AttestationType::Unrecognized => write!(f, "unrecognized"),
         }
     }
 }
@@ -299,6 +945,9 @@ pub fn verify_attestation_json(json: &str) -> VerificationResult {
         AttestationType::Signed => {
             VerificationResult::fail("Signed attestations require a verifier key")
         }
+        AttestationType::Bundle => {
+            VerificationResult::fail("Bundle attestations require a TrustConfig; use verify_bundle")
+        }
         AttestationType::Dbom => {
             // Basic DBOM validation
             let dbom: crate::types::Dbom = match serde_json::from_str(json) {
@@ -311,29 +960,256 @@ pub fn verify_attestation_json(json: &str) -> VerificationResult {
                 Err(e) => VerificationResult::fail(format!("DBOM validation failed: {}", e)),
             }
         }
+        AttestationType::SlsaProvenanceV02 => {
+            let attestation: SlsaProvenanceV02Attestation = match serde_json::from_str(json) {
+                Ok(a) => a,
+                Err(e) => return VerificationResult::fail(format!("Parse error: {}", e)),
+            };
+            match attestation.validate() {
+                Ok(()) => VerificationResult::pass(MakotoLevel::L1)
+                    .with_message("SLSA Provenance v0.2 structure is valid"),
+                Err(e) => VerificationResult::fail(format!("Validation failed: {}", e)),
+            }
+        }
+        AttestationType::SlsaProvenanceV1 => {
+            let attestation: SlsaProvenanceV1Attestation = match serde_json::from_str(json) {
+                Ok(a) => a,
+                Err(e) => return VerificationResult::fail(format!("Parse error: {}", e)),
+            };
+            match attestation.validate() {
+                Ok(()) => VerificationResult::pass(MakotoLevel::L1)
+                    .with_message("SLSA Provenance v1 structure is valid"),
+                Err(e) => VerificationResult::fail(format!("Validation failed: {}", e)),
+            }
+        }
+        AttestationType::Scai => {
+            let attestation: ScaiAttestation = match serde_json::from_str(json) {
+                Ok(a) => a,
+                Err(e) => return VerificationResult::fail(format!("Parse error: {}", e)),
+            };
+            match attestation.validate() {
+                Ok(()) => VerificationResult::pass(MakotoLevel::L1)
+                    .with_message("SCAI attribute report structure is valid"),
+                Err(e) => VerificationResult::fail(format!("Validation failed: {}", e)),
+            }
+        }
+        AttestationType::Unrecognized => {
+            let statement: RawStatement = match serde_json::from_str(json) {
+                Ok(s) => s,
+                Err(e) => return VerificationResult::fail(format!("Parse error: {}", e)),
+            };
+            let predicate_type = statement.predicate_type.clone();
+            match statement.validate_envelope() {
+                Ok(()) => VerificationResult::pass(MakotoLevel::L1)
+                    .with_message("in-toto Statement envelope is valid")
+                    .with_warning(format!(
+                        "predicate type '{}' is not modeled by this SDK; predicate left unvalidated",
+                        predicate_type
+                    )),
+                Err(e) => VerificationResult::fail(format!("Validation failed: {}", e)),
+            }
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::types::origin::{Collector, Origin};
-    use crate::types::common::{CollectionMethod, SourceType};
-    use crate::types::Subject;
-    use chrono::Utc;
-
-    #[test]
-    fn test_verify_digest() {
-        let data = b"hello world";
-        let hash = sha256_hex(data);
-        let digest = Digest::new(hash);
+/// Options for [`verify_attestation_json_with_options`]'s validity-window
+/// check.
+///
+/// There's already a place for an attestation's own claimed validity
+/// window: [`crate::signing::AttestationClaims::not_before`]/`expires_at`,
+/// carried on [`SignedAttestation::claims`]. That's a different thing from
+/// [`crate::signing::MakotoVerifier::not_before`]/`not_after` (the signing
+/// *key's* validity window, checked by
+/// [`crate::signing::MakotoVerifier::check_validity_at`]) — this struct,
+/// like [`VerificationPolicy`], only ever looks at the attestation's own
+/// claims, never the key's. Rather than add a second, differently-named
+/// pair of fields alongside `claims.not_before`/`expires_at`, this reuses
+/// them directly.
+#[derive(Debug, Clone)]
+pub struct ValidationOptions {
+    /// Clock skew tolerance applied to both ends of the validity window.
+    pub leeway: chrono::Duration,
+    /// Whether to check the validity window at all. `false` skips straight
+    /// to the structural check, for callers that have already validated
+    /// time some other way (or deliberately don't care).
+    pub validate_time: bool,
+    /// The time to check the window against. `None` means "now" —
+    /// overriding it is mainly for tests and for callers replaying
+    /// historical attestations against the time they were received.
+    pub reference_time: Option<DateTime<Utc>>,
+}
 
-        assert!(verify_digest(&digest, data).unwrap());
+impl Default for ValidationOptions {
+    /// A few seconds of leeway, time validation on — consumers who opt into
+    /// this function shouldn't have to also opt into a clock-skew footgun,
+    /// and shouldn't silently trust a stale or not-yet-valid attestation by
+    /// forgetting to turn validation on.
+    fn default() -> Self {
+        Self {
+            leeway: chrono::Duration::seconds(5),
+            validate_time: true,
+            reference_time: None,
+        }
     }
+}
 
-    #[test]
-    fn test_verify_digest_mismatch() {
-        let digest = Digest::new("a".repeat(64));
+/// Verify an attestation envelope (`json`, a [`SignedAttestation`]) the way
+/// [`verify_attestation_json`] verifies a bare attestation, plus a
+/// validity-window check: [`verify_attestation_json`] and the
+/// `verify_*_structure` functions it dispatches to only check structural
+/// completeness, never whether the attestation has expired or isn't valid
+/// yet.
+///
+/// When `options.validate_time` is set and `signed.claims` carries a
+/// `not_before`/`expires_at`, fails closed with a distinct "not yet valid"
+/// or "expired" message if `reference_time` (default: now) falls outside
+/// `[not_before - leeway, expires_at + leeway]`. An envelope with no claims,
+/// or claims with no validity window set, has nothing to check and passes
+/// this step. Only after the time check passes is the decoded payload
+/// handed to [`verify_attestation_json`] for its structural check — this
+/// does not itself verify the envelope's signature; pair it with
+/// [`verify_signed_attestation_with_policy`] or
+/// [`verify_attestation_json_with_trust`] for that.
+pub fn verify_attestation_json_with_options(
+    json: &str,
+    options: &ValidationOptions,
+) -> VerificationResult {
+    let signed: SignedAttestation = match serde_json::from_str(json) {
+        Ok(s) => s,
+        Err(e) => return VerificationResult::fail(format!("Parse error: {}", e)),
+    };
+
+    if options.validate_time {
+        if let Some(claims) = &signed.claims {
+            let reference_time = options.reference_time.unwrap_or_else(Utc::now);
+
+            if let Some(not_before) = claims.not_before {
+                if reference_time + options.leeway < not_before {
+                    return VerificationResult::fail(format!(
+                        "attestation not yet valid: not_before={}, reference_time={}",
+                        not_before, reference_time
+                    ));
+                }
+            }
+
+            if let Some(expires_at) = claims.expires_at {
+                if reference_time - options.leeway > expires_at {
+                    return VerificationResult::fail(format!(
+                        "attestation expired: expires_at={}, reference_time={}",
+                        expires_at, reference_time
+                    ));
+                }
+            }
+        }
+    }
+
+    let payload_bytes = match BASE64.decode(&signed.payload) {
+        Ok(b) => b,
+        Err(e) => return VerificationResult::fail(format!("Invalid base64: {}", e)),
+    };
+    let payload_json = match String::from_utf8(payload_bytes) {
+        Ok(s) => s,
+        Err(e) => return VerificationResult::fail(format!("Invalid UTF-8 payload: {}", e)),
+    };
+
+    verify_attestation_json(&payload_json)
+}
+
+/// Verify a signed envelope (`json`) against a discovered trust root
+/// instead of a caller-supplied public key: decodes the payload, reads the
+/// attested identity (a `predicate.collector.id` or `predicate.executor.id`
+/// URI), and only accepts a signature whose `key_id` is both present in
+/// `keyring` and currently delegated for that identity in `trust`.
+///
+/// This is the identity-aware counterpart to [`verify_attestation_json`],
+/// which (for a `Signed` envelope) has no way to know which key is
+/// appropriate without one being handed to it.
+pub fn verify_attestation_json_with_trust(
+    json: &str,
+    keyring: &MakotoKeyring,
+    trust: &TrustRoot,
+) -> VerificationResult {
+    let signed: SignedAttestation = match serde_json::from_str(json) {
+        Ok(s) => s,
+        Err(e) => return VerificationResult::fail(format!("Parse error: {}", e)),
+    };
+
+    let payload: serde_json::Value = match signed.decode_payload() {
+        Ok(v) => v,
+        Err(e) => return VerificationResult::fail(format!("Payload decode failed: {}", e)),
+    };
+
+    let identity = match attested_identity(&payload) {
+        Some(id) => id,
+        None => {
+            return VerificationResult::fail(
+                "could not determine an attested identity (predicate.collector.id or \
+                 predicate.executor.id) from the payload",
+            )
+        }
+    };
+
+    for sig in &signed.signatures {
+        if !trust.is_delegated(&identity, &sig.keyid) {
+            continue;
+        }
+
+        let verifier = match keyring.get(&sig.keyid) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        match signed.verify(verifier) {
+            Ok(true) => {
+                return VerificationResult::pass(MakotoLevel::L2).with_message(format!(
+                    "signature by key '{}', delegated for '{}', verified",
+                    sig.keyid, identity
+                ));
+            }
+            Ok(false) => continue,
+            Err(e) => return VerificationResult::fail(format!("verification error: {}", e)),
+        }
+    }
+
+    VerificationResult::fail(format!(
+        "no signature from a key currently delegated for '{}' verified",
+        identity
+    ))
+}
+
+/// Read the attested identity URI out of a decoded payload's predicate —
+/// `collector.id` for origin/stream-window predicates, `executor.id` for
+/// transform predicates.
+fn attested_identity(payload: &serde_json::Value) -> Option<String> {
+    let predicate = payload.get("predicate")?;
+    predicate
+        .get("collector")
+        .and_then(|c| c.get("id"))
+        .or_else(|| predicate.get("executor").and_then(|e| e.get("id")))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::origin::{Collector, Origin};
+    use crate::types::common::{CollectionMethod, SourceType};
+    use crate::types::Subject;
+    use chrono::Utc;
+
+    #[test]
+    fn test_verify_digest() {
+        let data = b"hello world";
+        let hash = sha256_hex(data);
+        let digest = Digest::new(hash);
+
+        assert!(verify_digest(&digest, data).unwrap());
+    }
+
+    #[test]
+    fn test_verify_digest_mismatch() {
+        let digest = Digest::new("a".repeat(64));
         let result = verify_digest(&digest, b"different data");
         assert!(result.is_err());
     }
@@ -361,6 +1237,100 @@ mod tests {
         assert_eq!(result.level, Some(MakotoLevel::L1));
     }
 
+    struct AlwaysValidDelegation;
+
+    impl crate::authz::DidVerifier for AlwaysValidDelegation {
+        fn verify(&self, _issuer: &crate::authz::Did, _message: &[u8], signature: &str) -> Result<bool> {
+            Ok(signature == "valid")
+        }
+    }
+
+    fn transform_with_authorization(
+        authorization: Option<crate::authz::UcanChain>,
+    ) -> TransformAttestation {
+        use crate::types::common::IsolationLevel;
+        use crate::types::transform::{Executor, InputReference, TransformDefinition};
+
+        let mut builder = TransformAttestation::builder()
+            .subject(Subject::new("dataset:output", Digest::new("b".repeat(64))))
+            .input(InputReference::new("dataset:input", Digest::new("a".repeat(64))))
+            .transform(TransformDefinition::new(
+                "https://makoto.dev/transforms/filter",
+                "test",
+            ))
+            .executor(Executor::new("did:key:executor").with_isolation(IsolationLevel::Process));
+
+        if let Some(chain) = authorization {
+            builder = builder.authorization(chain);
+        }
+
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_verify_transform_structure_with_authorization_accepts_valid_chain() {
+        use crate::authz::{Capability, CapabilityAction, UcanChain, UcanToken};
+
+        let chain = UcanChain::new(vec![UcanToken::new(
+            "did:key:root",
+            "did:key:executor",
+            vec![
+                Capability::new("dataset:input", CapabilityAction::Transform),
+                Capability::new("dataset:output", CapabilityAction::Transform),
+            ],
+        )
+        .with_signature("valid")]);
+        let attestation = transform_with_authorization(Some(chain));
+
+        let result = verify_transform_structure_with_authorization(
+            &attestation,
+            &["did:key:root".to_string()],
+            &AlwaysValidDelegation,
+            Utc::now(),
+        );
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L1));
+    }
+
+    #[test]
+    fn test_verify_transform_structure_with_authorization_rejects_missing_chain() {
+        let attestation = transform_with_authorization(None);
+
+        let result = verify_transform_structure_with_authorization(
+            &attestation,
+            &["did:key:root".to_string()],
+            &AlwaysValidDelegation,
+            Utc::now(),
+        );
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_transform_structure_with_authorization_rejects_expired_token() {
+        use crate::authz::{Capability, CapabilityAction, UcanChain, UcanToken};
+
+        let chain = UcanChain::new(vec![UcanToken::new(
+            "did:key:root",
+            "did:key:executor",
+            vec![
+                Capability::new("dataset:input", CapabilityAction::Transform),
+                Capability::new("dataset:output", CapabilityAction::Transform),
+            ],
+        )
+        .with_expires_at(Utc::now() - chrono::Duration::days(1))
+        .with_signature("valid")]);
+        let attestation = transform_with_authorization(Some(chain));
+
+        let result = verify_transform_structure_with_authorization(
+            &attestation,
+            &["did:key:root".to_string()],
+            &AlwaysValidDelegation,
+            Utc::now(),
+        );
+        assert!(!result.valid);
+        assert!(result.messages.iter().any(|m| m.contains("expired")));
+    }
+
     #[test]
     fn test_detect_attestation_type() {
         let origin_json = r#"{"_type":"https://in-toto.io/Statement/v1","predicateType":"https://makoto.dev/origin/v1","subject":[],"predicate":{}}"#;
@@ -380,5 +1350,810 @@ mod tests {
             detect_attestation_type(dbom_json).unwrap(),
             AttestationType::Dbom
         );
+
+        let bundle_json = r#"{"mediaType":"application/vnd.makoto.bundle+json","bundleVersion":"1","signed":{}}"#;
+        assert_eq!(
+            detect_attestation_type(bundle_json).unwrap(),
+            AttestationType::Bundle
+        );
+
+        let slsa_v02_json = r#"{"_type":"https://in-toto.io/Statement/v1","predicateType":"https://slsa.dev/provenance/v0.2","subject":[],"predicate":{}}"#;
+        assert_eq!(
+            detect_attestation_type(slsa_v02_json).unwrap(),
+            AttestationType::SlsaProvenanceV02
+        );
+
+        let slsa_v1_json = r#"{"_type":"https://in-toto.io/Statement/v1","predicateType":"https://slsa.dev/provenance/v1","subject":[],"predicate":{}}"#;
+        assert_eq!(
+            detect_attestation_type(slsa_v1_json).unwrap(),
+            AttestationType::SlsaProvenanceV1
+        );
+
+        let scai_json = r#"{"_type":"https://in-toto.io/Statement/v1","predicateType":"https://in-toto.io/attestation/scai/attribute-report/v0.2","subject":[],"predicate":{}}"#;
+        assert_eq!(detect_attestation_type(scai_json).unwrap(), AttestationType::Scai);
+
+        let unrecognized_json = r#"{"_type":"https://in-toto.io/Statement/v1","predicateType":"https://example.com/something-else/v1","subject":[],"predicate":{}}"#;
+        assert_eq!(
+            detect_attestation_type(unrecognized_json).unwrap(),
+            AttestationType::Unrecognized
+        );
+    }
+
+    #[test]
+    fn test_verify_attestation_json_slsa_v02() {
+        let json = r#"{"_type":"https://in-toto.io/Statement/v1","subject":[{"name":"dataset:test","digest":{"sha256":"aaaa"}}],"predicateType":"https://slsa.dev/provenance/v0.2","predicate":{"builder":{"id":"https://ci.example.com/builder/1"},"buildType":"https://ci.example.com/build-types/default"}}"#;
+        let result = verify_attestation_json(json);
+        assert!(result.valid);
+        assert_eq!(result.level, Some(MakotoLevel::L1));
+    }
+
+    #[test]
+    fn test_verify_attestation_json_scai() {
+        let json = r#"{"_type":"https://in-toto.io/Statement/v1","subject":[{"name":"dataset:test","digest":{"sha256":"aaaa"}}],"predicateType":"https://in-toto.io/attestation/scai/attribute-report/v0.2","predicate":{"attributes":[{"attribute":"TRUSTED_BUILD_SYSTEM"}]}}"#;
+        let result = verify_attestation_json(json);
+        assert!(result.valid);
+        assert_eq!(result.level, Some(MakotoLevel::L1));
+    }
+
+    #[test]
+    fn test_verify_attestation_json_unrecognized_predicate_passes_with_warning() {
+        let json = r#"{"_type":"https://in-toto.io/Statement/v1","subject":[{"name":"dataset:test","digest":{"sha256":"aaaa"}}],"predicateType":"https://example.com/something-else/v1","predicate":{"anything":"goes"}}"#;
+        let result = verify_attestation_json(json);
+        assert!(result.valid);
+        assert!(!result.warnings.is_empty());
+    }
+
+    fn signed_origin_json(signer: &crate::signing::MakotoSigner, collector_id: &str) -> String {
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(Collector::new(collector_id))
+            .build()
+            .unwrap();
+        let signed = SignedAttestation::sign(&attestation, signer).unwrap();
+        serde_json::to_string(&signed).unwrap()
+    }
+
+    #[test]
+    fn test_verify_attestation_json_with_trust_accepts_delegated_key() {
+        use crate::signing::{MakotoKeyring, MakotoSigner, SignatureAlgorithm};
+        use crate::trust::TrustManifest;
+
+        let collector_id = "https://expanso.io/collectors/iot-001";
+        let signer = MakotoSigner::generate();
+        let root = MakotoSigner::generate();
+
+        let manifest = TrustManifest::new(1, Utc::now() + chrono::Duration::days(30))
+            .with_delegation(collector_id, vec![signer.key_id().to_string()]);
+        let signed_manifest = SignedAttestation::sign(&manifest, &root).unwrap();
+        let root_keys = VerifierSet::new().with_verifier(root.verifying_key(), SignatureAlgorithm::EcdsaP256.label());
+        let trust = TrustRoot::from_signed(&signed_manifest, &root_keys, 1, Utc::now()).unwrap();
+
+        let mut keyring = MakotoKeyring::new();
+        keyring.add(signer.verifying_key());
+
+        let json = signed_origin_json(&signer, collector_id);
+        let result = verify_attestation_json_with_trust(&json, &keyring, &trust);
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L2));
+    }
+
+    #[test]
+    fn test_verify_attestation_json_with_trust_rejects_undelegated_key() {
+        use crate::signing::{MakotoKeyring, MakotoSigner, SignatureAlgorithm};
+        use crate::trust::TrustManifest;
+
+        let collector_id = "https://expanso.io/collectors/iot-001";
+        let signer = MakotoSigner::generate();
+        let stranger = MakotoSigner::generate();
+        let root = MakotoSigner::generate();
+
+        // Manifest delegates to `stranger`'s key, not the one that actually signed.
+        let manifest = TrustManifest::new(1, Utc::now() + chrono::Duration::days(30))
+            .with_delegation(collector_id, vec![stranger.key_id().to_string()]);
+        let signed_manifest = SignedAttestation::sign(&manifest, &root).unwrap();
+        let root_keys = VerifierSet::new().with_verifier(root.verifying_key(), SignatureAlgorithm::EcdsaP256.label());
+        let trust = TrustRoot::from_signed(&signed_manifest, &root_keys, 1, Utc::now()).unwrap();
+
+        let mut keyring = MakotoKeyring::new();
+        keyring.add(signer.verifying_key());
+
+        let json = signed_origin_json(&signer, collector_id);
+        let result = verify_attestation_json_with_trust(&json, &keyring, &trust);
+        assert!(!result.valid);
+    }
+
+    fn hash_pair_rfc6962(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        hash::make_hasher(HashAlgorithm::Sha256, HashMode::Rfc6962)
+            .unwrap()
+            .hash_pair(left, right)
+    }
+
+    fn leaf_hash_rfc6962(data: &[u8]) -> [u8; 32] {
+        hash::make_hasher(HashAlgorithm::Sha256, HashMode::Rfc6962)
+            .unwrap()
+            .hash_leaf(data)
+    }
+
+    #[test]
+    fn test_verify_transparency_inclusion_passes_for_valid_proof() {
+        let signer = crate::signing::MakotoSigner::generate();
+
+        let leaf0 = leaf_hash_rfc6962(b"entry-0");
+        let leaf1 = leaf_hash_rfc6962(b"entry-1");
+        let root = hash_pair_rfc6962(&leaf0, &leaf1);
+
+        let checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 2, hex::encode(root), &signer).unwrap();
+        let proof = InclusionProof::new(0, 2, vec![hex::encode(leaf1)]);
+
+        let result =
+            verify_transparency_inclusion(b"entry-0", &proof, &checkpoint, &signer.verifying_key());
+        assert!(result.valid);
+        assert_eq!(result.level, Some(MakotoLevel::L3));
+    }
+
+    #[test]
+    fn test_verify_transparency_inclusion_rejects_wrong_root() {
+        let signer = crate::signing::MakotoSigner::generate();
+
+        let leaf1 = leaf_hash_rfc6962(b"entry-1");
+
+        let checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 2, "a".repeat(64), &signer).unwrap();
+        let proof = InclusionProof::new(0, 2, vec![hex::encode(leaf1)]);
+
+        let result =
+            verify_transparency_inclusion(b"entry-0", &proof, &checkpoint, &signer.verifying_key());
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_transparency_inclusion_rejects_bad_checkpoint_signature() {
+        let signer = crate::signing::MakotoSigner::generate();
+        let other = crate::signing::MakotoSigner::generate();
+
+        let leaf0 = leaf_hash_rfc6962(b"entry-0");
+        let leaf1 = leaf_hash_rfc6962(b"entry-1");
+        let root = hash_pair_rfc6962(&leaf0, &leaf1);
+
+        let checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 2, hex::encode(root), &signer).unwrap();
+        let proof = InclusionProof::new(0, 2, vec![hex::encode(leaf1)]);
+
+        let result =
+            verify_transparency_inclusion(b"entry-0", &proof, &checkpoint, &other.verifying_key());
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_transparency_inclusion_rejects_wrong_audit_path_length() {
+        let signer = crate::signing::MakotoSigner::generate();
+
+        let checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 4, "a".repeat(64), &signer).unwrap();
+        // A tree of size 4 needs a 2-entry audit path, not 1.
+        let proof = InclusionProof::new(0, 4, vec!["b".repeat(64)]);
+
+        let result =
+            verify_transparency_inclusion(b"entry-0", &proof, &checkpoint, &signer.verifying_key());
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_transparency_inclusion_passes_for_non_power_of_two_tree_size() {
+        // A 5-entry log, proving entry 4 (the lone trailing leaf). Its real
+        // RFC 6962 audit path is a single hash, not ceil(log2(5)) = 3 entries.
+        let signer = crate::signing::MakotoSigner::generate();
+
+        let leaves: Vec<[u8; 32]> = (0..5).map(|i| leaf_hash_rfc6962(format!("entry-{i}").as_bytes())).collect();
+        let mth_0_4 = hash_pair_rfc6962(
+            &hash_pair_rfc6962(&leaves[0], &leaves[1]),
+            &hash_pair_rfc6962(&leaves[2], &leaves[3]),
+        );
+        let root = hash_pair_rfc6962(&mth_0_4, &leaves[4]);
+
+        let checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 5, hex::encode(root), &signer).unwrap();
+        let proof = InclusionProof::new(4, 5, vec![hex::encode(mth_0_4)]);
+
+        let result =
+            verify_transparency_inclusion(b"entry-4", &proof, &checkpoint, &signer.verifying_key());
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L3));
+    }
+
+    #[test]
+    fn test_verify_inclusion_passes_for_valid_entry() {
+        let log_signer = crate::signing::MakotoSigner::generate();
+        let attestation_signer = crate::signing::MakotoSigner::generate();
+
+        let leaf0 = leaf_hash_rfc6962(b"entry-0");
+        let leaf1 = leaf_hash_rfc6962(b"entry-1");
+        let root = hash_pair_rfc6962(&leaf0, &leaf1);
+
+        let checkpoint = LogCheckpoint::sign(
+            "https://log.example.com",
+            2,
+            hex::encode(root),
+            &log_signer,
+        )
+        .unwrap();
+        let proof = InclusionProof::new(0, 2, vec![hex::encode(leaf1)]);
+        let signed = signed_origin_with_claims(&attestation_signer, None);
+        let entry = TransparencyLogEntry::new(signed, proof, checkpoint);
+
+        let result = verify_inclusion(&entry, b"entry-0", &log_signer.verifying_key()).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn test_verify_inclusion_rejects_wrong_leaf() {
+        let log_signer = crate::signing::MakotoSigner::generate();
+        let attestation_signer = crate::signing::MakotoSigner::generate();
+
+        let leaf0 = leaf_hash_rfc6962(b"entry-0");
+        let leaf1 = leaf_hash_rfc6962(b"entry-1");
+        let root = hash_pair_rfc6962(&leaf0, &leaf1);
+
+        let checkpoint = LogCheckpoint::sign(
+            "https://log.example.com",
+            2,
+            hex::encode(root),
+            &log_signer,
+        )
+        .unwrap();
+        let proof = InclusionProof::new(0, 2, vec![hex::encode(leaf1)]);
+        let signed = signed_origin_with_claims(&attestation_signer, None);
+        let entry = TransparencyLogEntry::new(signed, proof, checkpoint);
+
+        let result = verify_inclusion(&entry, b"not-the-logged-entry", &log_signer.verifying_key()).unwrap();
+        assert!(!result);
+    }
+
+    fn signed_origin_with_claims(
+        signer: &crate::signing::MakotoSigner,
+        claims: Option<crate::signing::AttestationClaims>,
+    ) -> SignedAttestation {
+        let origin = Origin::new(
+            "https://api.example.com/data",
+            SourceType::Api,
+            CollectionMethod::Pull,
+            Utc::now(),
+        );
+        let collector = Collector::new("https://example.com/collector/001");
+        let attestation = OriginAttestation::builder()
+            .subject(Subject::new("dataset:test", Digest::new("a".repeat(64))))
+            .origin(origin)
+            .collector(collector)
+            .build()
+            .unwrap();
+
+        let mut signed = SignedAttestation::sign(&attestation, signer).unwrap();
+        if let Some(claims) = claims {
+            signed = signed.with_claims(claims);
+        }
+        signed
+    }
+
+    fn allowed(algorithms: &[&str], issuers: &[&str]) -> VerificationPolicy {
+        VerificationPolicy::new(
+            algorithms.iter().map(|s| s.to_string()).collect(),
+            issuers.iter().map(|s| s.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_verify_signed_attestation_with_policy_passes() {
+        use crate::signing::AttestationClaims;
+
+        let signer = crate::signing::MakotoSigner::generate();
+        let claims = AttestationClaims::new("collector-001");
+        let signed = signed_origin_with_claims(&signer, Some(claims));
+        let policy = allowed(&[crate::signing::SIGNATURE_ALGORITHM], &["collector-001"]);
+
+        let result = verify_signed_attestation_with_policy::<OriginAttestation>(
+            &signed,
+            &signer.verifying_key(),
+            &policy,
+        );
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L2));
+    }
+
+    #[test]
+    fn test_verify_signed_attestation_with_policy_rejects_empty_algorithm_allowlist() {
+        let signer = crate::signing::MakotoSigner::generate();
+        let signed = signed_origin_with_claims(&signer, None);
+        let policy = allowed(&[], &["collector-001"]);
+
+        let result = verify_signed_attestation_with_policy::<OriginAttestation>(
+            &signed,
+            &signer.verifying_key(),
+            &policy,
+        );
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_signed_attestation_with_policy_rejects_unknown_issuer() {
+        use crate::signing::AttestationClaims;
+
+        let signer = crate::signing::MakotoSigner::generate();
+        let claims = AttestationClaims::new("someone-else");
+        let signed = signed_origin_with_claims(&signer, Some(claims));
+        let policy = allowed(&[crate::signing::SIGNATURE_ALGORITHM], &["collector-001"]);
+
+        let result = verify_signed_attestation_with_policy::<OriginAttestation>(
+            &signed,
+            &signer.verifying_key(),
+            &policy,
+        );
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_signed_attestation_with_policy_rejects_expired() {
+        use crate::signing::AttestationClaims;
+
+        let signer = crate::signing::MakotoSigner::generate();
+        let claims = AttestationClaims::new("collector-001")
+            .with_expires_at(Utc::now() - chrono::Duration::days(1));
+        let signed = signed_origin_with_claims(&signer, Some(claims));
+        let policy = allowed(&[crate::signing::SIGNATURE_ALGORITHM], &["collector-001"]);
+
+        let result = verify_signed_attestation_with_policy::<OriginAttestation>(
+            &signed,
+            &signer.verifying_key(),
+            &policy,
+        );
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_signed_attestation_with_policy_allows_expired_within_leeway() {
+        use crate::signing::AttestationClaims;
+
+        let signer = crate::signing::MakotoSigner::generate();
+        let claims = AttestationClaims::new("collector-001")
+            .with_expires_at(Utc::now() - chrono::Duration::seconds(30));
+        let signed = signed_origin_with_claims(&signer, Some(claims));
+        let policy = allowed(&[crate::signing::SIGNATURE_ALGORITHM], &["collector-001"])
+            .with_leeway_secs(120);
+
+        let result = verify_signed_attestation_with_policy::<OriginAttestation>(
+            &signed,
+            &signer.verifying_key(),
+            &policy,
+        );
+        assert!(result.valid, "{:?}", result.messages);
+    }
+
+    #[test]
+    fn test_verify_attestation_json_with_options_passes_without_claims() {
+        let signer = crate::signing::MakotoSigner::generate();
+        let signed = signed_origin_with_claims(&signer, None);
+        let json = serde_json::to_string(&signed).unwrap();
+
+        let result = verify_attestation_json_with_options(&json, &ValidationOptions::default());
+        assert!(result.valid, "{:?}", result.messages);
+    }
+
+    #[test]
+    fn test_verify_attestation_json_with_options_rejects_not_yet_valid() {
+        use crate::signing::AttestationClaims;
+
+        let signer = crate::signing::MakotoSigner::generate();
+        let claims = AttestationClaims::new("collector-001")
+            .with_not_before(Utc::now() + chrono::Duration::days(1));
+        let signed = signed_origin_with_claims(&signer, Some(claims));
+        let json = serde_json::to_string(&signed).unwrap();
+
+        let result = verify_attestation_json_with_options(&json, &ValidationOptions::default());
+        assert!(!result.valid);
+        assert!(result.messages.iter().any(|m| m.contains("not yet valid")));
+    }
+
+    #[test]
+    fn test_verify_attestation_json_with_options_rejects_expired() {
+        use crate::signing::AttestationClaims;
+
+        let signer = crate::signing::MakotoSigner::generate();
+        let claims = AttestationClaims::new("collector-001")
+            .with_expires_at(Utc::now() - chrono::Duration::days(1));
+        let signed = signed_origin_with_claims(&signer, Some(claims));
+        let json = serde_json::to_string(&signed).unwrap();
+
+        let result = verify_attestation_json_with_options(&json, &ValidationOptions::default());
+        assert!(!result.valid);
+        assert!(result.messages.iter().any(|m| m.contains("expired")));
+    }
+
+    #[test]
+    fn test_verify_attestation_json_with_options_allows_expired_within_leeway() {
+        use crate::signing::AttestationClaims;
+
+        let signer = crate::signing::MakotoSigner::generate();
+        let claims = AttestationClaims::new("collector-001")
+            .with_expires_at(Utc::now() - chrono::Duration::seconds(30));
+        let signed = signed_origin_with_claims(&signer, Some(claims));
+        let json = serde_json::to_string(&signed).unwrap();
+
+        let options = ValidationOptions {
+            leeway: chrono::Duration::seconds(120),
+            validate_time: true,
+            reference_time: None,
+        };
+        let result = verify_attestation_json_with_options(&json, &options);
+        assert!(result.valid, "{:?}", result.messages);
+    }
+
+    #[test]
+    fn test_verify_attestation_json_with_options_skips_check_when_disabled() {
+        use crate::signing::AttestationClaims;
+
+        let signer = crate::signing::MakotoSigner::generate();
+        let claims = AttestationClaims::new("collector-001")
+            .with_expires_at(Utc::now() - chrono::Duration::days(1));
+        let signed = signed_origin_with_claims(&signer, Some(claims));
+        let json = serde_json::to_string(&signed).unwrap();
+
+        let options = ValidationOptions {
+            leeway: chrono::Duration::seconds(5),
+            validate_time: false,
+            reference_time: None,
+        };
+        let result = verify_attestation_json_with_options(&json, &options);
+        assert!(result.valid, "{:?}", result.messages);
+    }
+
+    #[test]
+    fn test_verify_signed_attestation_multi_passes_for_matching_key() {
+        let signer = crate::signing::MakotoSigner::generate();
+        let signed = signed_origin_with_claims(&signer, None);
+        let set = VerifierSet::new().with_verifier(signer.verifying_key(), SIGNATURE_ALGORITHM);
+
+        let result = verify_signed_attestation_multi::<OriginAttestation>(&signed, &set);
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L2));
+    }
+
+    #[test]
+    fn test_verify_signed_attestation_multi_rejects_unknown_key_id() {
+        let signer = crate::signing::MakotoSigner::generate();
+        let other = crate::signing::MakotoSigner::generate();
+        let signed = signed_origin_with_claims(&signer, None);
+        let set = VerifierSet::new().with_verifier(other.verifying_key(), SIGNATURE_ALGORITHM);
+
+        let result = verify_signed_attestation_multi::<OriginAttestation>(&signed, &set);
+        assert!(!result.valid);
+        assert!(result.messages.iter().any(|m| m.contains("no verifier in the set matches key id")));
+    }
+
+    #[test]
+    fn test_verify_signed_attestation_multi_rejects_disallowed_algorithm() {
+        let signer = crate::signing::MakotoSigner::generate();
+        let signed = signed_origin_with_claims(&signer, None);
+        let set = VerifierSet::new().with_verifier(signer.verifying_key(), "EdDSA");
+
+        let result = verify_signed_attestation_multi::<OriginAttestation>(&signed, &set);
+        assert!(!result.valid);
+        assert!(result.messages.iter().any(|m| m.contains("only allowed with algorithm")));
+    }
+
+    #[test]
+    fn test_verify_signed_attestation_multi_rejects_empty_set() {
+        let signer = crate::signing::MakotoSigner::generate();
+        let signed = signed_origin_with_claims(&signer, None);
+        let set = VerifierSet::new();
+
+        let result = verify_signed_attestation_multi::<OriginAttestation>(&signed, &set);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_threshold_passes_with_enough_distinct_signers() {
+        let collector = crate::signing::MakotoSigner::generate();
+        let notary = crate::signing::MakotoSigner::generate();
+        let mut signed = signed_origin_with_claims(&collector, None);
+        signed.add_signature(&notary).unwrap();
+
+        let set = VerifierSet::new()
+            .with_verifier(collector.verifying_key(), SIGNATURE_ALGORITHM)
+            .with_verifier(notary.verifying_key(), SIGNATURE_ALGORITHM);
+
+        let result = verify_threshold(&signed, &set, 2);
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L2));
+        assert!(result.messages.iter().any(|m| m.contains(collector.key_id())));
+        assert!(result.messages.iter().any(|m| m.contains(notary.key_id())));
+    }
+
+    #[test]
+    fn test_verify_threshold_fails_below_minimum() {
+        let collector = crate::signing::MakotoSigner::generate();
+        let signed = signed_origin_with_claims(&collector, None);
+
+        let set = VerifierSet::new().with_verifier(collector.verifying_key(), SIGNATURE_ALGORITHM);
+
+        let result = verify_threshold(&signed, &set, 2);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_threshold_ignores_unknown_keyids() {
+        let collector = crate::signing::MakotoSigner::generate();
+        let stranger = crate::signing::MakotoSigner::generate();
+        let mut signed = signed_origin_with_claims(&collector, None);
+        signed.add_signature(&stranger).unwrap();
+
+        let set = VerifierSet::new().with_verifier(collector.verifying_key(), SIGNATURE_ALGORITHM);
+
+        let result = verify_threshold(&signed, &set, 1);
+        assert!(result.valid, "{:?}", result.messages);
+    }
+
+    #[test]
+    fn test_verify_threshold_does_not_double_count_duplicate_signatures() {
+        let collector = crate::signing::MakotoSigner::generate();
+        let mut signed = signed_origin_with_claims(&collector, None);
+        let duplicate = signed.signatures[0].clone();
+        signed.signatures.push(duplicate);
+
+        let set = VerifierSet::new().with_verifier(collector.verifying_key(), SIGNATURE_ALGORITHM);
+
+        let result = verify_threshold(&signed, &set, 2);
+        assert!(!result.valid);
+    }
+
+    fn window_with_chain(
+        name: &str,
+        root: &str,
+        chain: Option<crate::types::stream_window::ChainDescriptor>,
+    ) -> StreamWindowAttestation {
+        use crate::types::stream_window::{
+            IntegrityDescriptor, MerkleTreeDescriptor, StreamDescriptor, WindowDescriptor,
+        };
+
+        let merkle = MerkleTreeDescriptor::new(HashAlgorithm::Sha256, 10, root);
+        let mut integrity = IntegrityDescriptor::new(merkle);
+        if let Some(chain) = chain {
+            integrity = integrity.with_chain(chain);
+        }
+
+        StreamWindowAttestation::builder()
+            .subject(Subject::new(name, Digest::new("b".repeat(64))))
+            .stream(StreamDescriptor::new("iot_sensors"))
+            .window(WindowDescriptor::tumbling("PT1M"))
+            .integrity(integrity)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_window_chain_passes_for_valid_sequence() {
+        use crate::types::stream_window::ChainDescriptor;
+
+        let w1 = window_with_chain("window_1", &"a".repeat(64), None);
+        let w2 = window_with_chain(
+            "window_2",
+            &"b".repeat(64),
+            Some(ChainDescriptor::linked("window_1", "a".repeat(64), 2)),
+        );
+
+        let result = verify_window_chain(&[w1, w2]);
+        assert!(result.valid, "{:?}", result.messages);
+        assert_eq!(result.level, Some(MakotoLevel::L1));
+    }
+
+    #[test]
+    fn test_verify_window_chain_empty_is_valid() {
+        assert!(verify_window_chain(&[]).valid);
+    }
+
+    #[test]
+    fn test_verify_window_chain_flags_root_mismatch() {
+        use crate::types::stream_window::ChainDescriptor;
+
+        let w1 = window_with_chain("window_1", &"a".repeat(64), None);
+        let w2 = window_with_chain(
+            "window_2",
+            &"b".repeat(64),
+            Some(ChainDescriptor::linked("window_1", "c".repeat(64), 2)),
+        );
+
+        let result = verify_window_chain(&[w1, w2]);
+        assert!(!result.valid);
+        assert!(result.messages[0].contains("window 1"));
+    }
+
+    #[test]
+    fn test_verify_window_chain_flags_missing_chain() {
+        let w1 = window_with_chain("window_1", &"a".repeat(64), None);
+        let w2 = window_with_chain("window_2", &"b".repeat(64), None);
+
+        let result = verify_window_chain(&[w1, w2]);
+        assert!(!result.valid);
+    }
+
+    fn mmr_window(
+        name: &str,
+        root_hex: &str,
+        chain: Option<crate::types::stream_window::ChainDescriptor>,
+    ) -> StreamWindowAttestation {
+        use crate::types::stream_window::{
+            IntegrityDescriptor, MerkleTreeDescriptor, MerkleTreeKind, StreamDescriptor,
+            WindowDescriptor,
+        };
+
+        let merkle =
+            MerkleTreeDescriptor::new(HashAlgorithm::Sha256, 10, root_hex).with_kind(MerkleTreeKind::MmrSha256);
+        let mut integrity = IntegrityDescriptor::new(merkle);
+        if let Some(chain) = chain {
+            integrity = integrity.with_chain(chain);
+        }
+
+        StreamWindowAttestation::builder()
+            .subject(Subject::new(name, Digest::new("b".repeat(64))))
+            .stream(StreamDescriptor::new("iot_sensors"))
+            .window(WindowDescriptor::tumbling("PT1M"))
+            .integrity(integrity)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_stream_window_structure_accepts_valid_mmr_consistency_proof() {
+        use crate::hash::MerkleMountainRange;
+        use crate::types::stream_window::ChainDescriptor;
+
+        let mut mmr = MerkleMountainRange::new();
+        for i in 0..3u8 {
+            mmr.append(&[i]).unwrap();
+        }
+        let old_root = mmr.root_hex().unwrap();
+        let old_size = mmr.leaf_count();
+
+        mmr.append(&[3]).unwrap();
+        mmr.append(&[4]).unwrap();
+        let new_root = mmr.root_hex().unwrap();
+        let new_size = mmr.leaf_count();
+
+        let proof = mmr.consistency_proof(old_size, new_size).unwrap().to_hex();
+        let chain = ChainDescriptor::linked("window_1", old_root, 2).with_consistency_proof(proof);
+        let window = mmr_window("window_2", &new_root, Some(chain));
+
+        let result = verify_stream_window_structure(&window);
+        assert!(result.valid, "{:?}", result.messages);
+    }
+
+    #[test]
+    fn test_verify_stream_window_structure_rejects_forged_mmr_consistency_proof() {
+        use crate::hash::MerkleMountainRange;
+        use crate::types::stream_window::ChainDescriptor;
+
+        let mut mmr = MerkleMountainRange::new();
+        for i in 0..3u8 {
+            mmr.append(&[i]).unwrap();
+        }
+        let old_root = mmr.root_hex().unwrap();
+        let old_size = mmr.leaf_count();
+
+        mmr.append(&[3]).unwrap();
+        mmr.append(&[4]).unwrap();
+        let new_size = mmr.leaf_count();
+
+        let proof = mmr.consistency_proof(old_size, new_size).unwrap().to_hex();
+        let chain = ChainDescriptor::linked("window_1", old_root, 2).with_consistency_proof(proof);
+        // Claim a root the proof was never generated against.
+        let window = mmr_window("window_2", &"f".repeat(64), Some(chain));
+
+        let result = verify_stream_window_structure(&window);
+        assert!(!result.valid);
+    }
+
+    fn window_with_records(records: &[Vec<u8>]) -> StreamWindowAttestation {
+        use crate::types::stream_window::{
+            IntegrityDescriptor, StreamDescriptor, WindowDescriptor,
+        };
+
+        let leaves: Vec<&[u8]> = records.iter().map(|r| r.as_slice()).collect();
+        let merkle = crate::types::stream_window::MerkleTreeDescriptor::from_leaves(
+            &leaves,
+            HashAlgorithm::Sha256,
+        )
+        .unwrap();
+
+        StreamWindowAttestation::builder()
+            .subject(Subject::new("window_1", Digest::new("b".repeat(64))))
+            .stream(StreamDescriptor::new("iot_sensors"))
+            .window(WindowDescriptor::tumbling("PT1M"))
+            .integrity(IntegrityDescriptor::new(merkle))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_verify_record_in_window_passes_for_valid_proof() {
+        let records: Vec<Vec<u8>> =
+            (0..4).map(|i| format!("record-{i}").into_bytes()).collect();
+        let attestation = window_with_records(&records);
+
+        let leaves: Vec<&[u8]> = records.iter().map(|r| r.as_slice()).collect();
+        let proof = crate::merkle::inclusion_proof(&leaves, HashAlgorithm::Sha256, 2).unwrap();
+        let audit_path: Vec<String> = proof.siblings.iter().map(hex::encode).collect();
+
+        let result = verify_record_in_window(&attestation, &records[2], 2, &audit_path);
+        assert!(result.valid);
+        assert_eq!(result.level, Some(MakotoLevel::L1));
+    }
+
+    #[test]
+    fn test_verify_record_in_window_passes_for_non_power_of_two_window() {
+        // 5 records: leaf 4's audit path carries an RFC 6962 carried-up
+        // placeholder for the levels where it's a lone trailing node.
+        let records: Vec<Vec<u8>> =
+            (0..5).map(|i| format!("record-{i}").into_bytes()).collect();
+        let attestation = window_with_records(&records);
+
+        let leaves: Vec<&[u8]> = records.iter().map(|r| r.as_slice()).collect();
+        let proof = crate::merkle::inclusion_proof(&leaves, HashAlgorithm::Sha256, 4).unwrap();
+        let audit_path: Vec<String> = proof.siblings.iter().map(hex::encode).collect();
+
+        let result = verify_record_in_window(&attestation, &records[4], 4, &audit_path);
+        assert!(result.valid, "{:?}", result.messages);
+    }
+
+    #[test]
+    fn test_verify_record_in_window_rejects_wrong_record() {
+        let records: Vec<Vec<u8>> =
+            (0..4).map(|i| format!("record-{i}").into_bytes()).collect();
+        let attestation = window_with_records(&records);
+
+        let leaves: Vec<&[u8]> = records.iter().map(|r| r.as_slice()).collect();
+        let proof = crate::merkle::inclusion_proof(&leaves, HashAlgorithm::Sha256, 2).unwrap();
+        let audit_path: Vec<String> = proof.siblings.iter().map(hex::encode).collect();
+
+        let result = verify_record_in_window(&attestation, b"tampered", 2, &audit_path);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_record_in_window_rejects_out_of_range_index() {
+        let records: Vec<Vec<u8>> =
+            (0..4).map(|i| format!("record-{i}").into_bytes()).collect();
+        let attestation = window_with_records(&records);
+
+        let result = verify_record_in_window(&attestation, &records[0], 9, &[]);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_record_in_window_rejects_wrong_path_length() {
+        let records: Vec<Vec<u8>> =
+            (0..4).map(|i| format!("record-{i}").into_bytes()).collect();
+        let attestation = window_with_records(&records);
+
+        let result = verify_record_in_window(&attestation, &records[0], 0, &["a".repeat(64)]);
+        assert!(!result.valid);
+    }
+
+    #[test]
+    fn test_verify_transparency_inclusion_rejects_leaf_index_out_of_range() {
+        let signer = crate::signing::MakotoSigner::generate();
+
+        let checkpoint =
+            LogCheckpoint::sign("https://log.example.com", 2, "a".repeat(64), &signer).unwrap();
+        let proof = InclusionProof::new(5, 2, vec!["b".repeat(64)]);
+
+        let result =
+            verify_transparency_inclusion(b"entry-0", &proof, &checkpoint, &signer.verifying_key());
+        assert!(!result.valid);
     }
 }
@@ -0,0 +1,286 @@
+//! Content hash verification for Makoto artifacts.
+//!
+//! This is distinct from [`crate::verification`], which checks attestation
+//! *structure* (required fields, hash lengths, predicate types). This module
+//! actually reads artifact bytes and recomputes their digest, so it's the
+//! engine that makes `VerificationInfo::input_hash_verified` mean something.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use sha2::{Digest as _, Sha256, Sha512};
+use sha3::Sha3_256;
+
+use crate::error::{MakotoError, Result};
+use crate::types::common::HashAlgorithm;
+use crate::types::transform::InputReference;
+use crate::types::{Digest, Subject, TransformAttestation};
+
+type Hasher = Box<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>;
+
+/// Computes and checks artifact digests, dispatched by [`HashAlgorithm`].
+///
+/// Ships with SHA-256, SHA-512, and SHA3-256 registered; additional
+/// algorithms can be added with [`DigestVerifier::register`].
+pub struct DigestVerifier {
+    hashers: HashMap<HashAlgorithm, Hasher>,
+}
+
+impl DigestVerifier {
+    /// Create a verifier with the default algorithm set registered.
+    pub fn new() -> Self {
+        let mut hashers: HashMap<HashAlgorithm, Hasher> = HashMap::new();
+        hashers.insert(HashAlgorithm::Sha256, Box::new(|data| Sha256::digest(data).to_vec()));
+        hashers.insert(HashAlgorithm::Sha512, Box::new(|data| Sha512::digest(data).to_vec()));
+        hashers.insert(HashAlgorithm::Sha3_256, Box::new(|data| Sha3_256::digest(data).to_vec()));
+        Self { hashers }
+    }
+
+    /// Register (or override) the hash function used for `algorithm`.
+    pub fn register(
+        mut self,
+        algorithm: HashAlgorithm,
+        hasher: impl Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static,
+    ) -> Self {
+        self.hashers.insert(algorithm, Box::new(hasher));
+        self
+    }
+
+    /// Read all of `reader`, compute its digest under `algorithm`, and
+    /// compare it constant-time against `expected_hex`.
+    pub fn verify(
+        &self,
+        expected_hex: &str,
+        algorithm: HashAlgorithm,
+        mut reader: impl Read,
+    ) -> Result<DigestCheck> {
+        let hasher = self.hashers.get(&algorithm).ok_or_else(|| {
+            MakotoError::InvalidAttestation(format!("no hasher registered for {:?}", algorithm))
+        })?;
+
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        let computed = hex::encode(hasher(&data));
+        let matches = constant_time_eq(computed.as_bytes(), expected_hex.as_bytes());
+
+        Ok(DigestCheck {
+            algorithm,
+            expected: expected_hex.to_string(),
+            computed,
+            matches,
+        })
+    }
+
+    /// Verify a recorded `Digest` against the bytes from `reader`, choosing
+    /// the expected hex value that corresponds to `algorithm`.
+    pub fn verify_digest(
+        &self,
+        digest: &Digest,
+        algorithm: HashAlgorithm,
+        reader: impl Read,
+    ) -> Result<DigestCheck> {
+        let expected = expected_hex_for(digest, algorithm).ok_or_else(|| {
+            MakotoError::MissingField(format!("digest has no value recorded for {:?}", algorithm))
+        })?;
+
+        self.verify(&expected, algorithm, reader)
+    }
+}
+
+impl Default for DigestVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Pull the hex digest matching `algorithm` out of a `Digest` record.
+fn expected_hex_for(digest: &Digest, algorithm: HashAlgorithm) -> Option<String> {
+    match algorithm {
+        HashAlgorithm::Sha256 => Some(digest.sha256.clone()),
+        HashAlgorithm::Sha512 => digest.sha512.clone(),
+        other => digest.additional.get(&algorithm_key(other)).cloned(),
+    }
+}
+
+fn algorithm_key(algorithm: HashAlgorithm) -> String {
+    match algorithm {
+        HashAlgorithm::Sha256 => "sha256".to_string(),
+        HashAlgorithm::Sha384 => "sha384".to_string(),
+        HashAlgorithm::Sha512 => "sha512".to_string(),
+        HashAlgorithm::Sha3_256 => "sha3-256".to_string(),
+        HashAlgorithm::Sha3_384 => "sha3-384".to_string(),
+        HashAlgorithm::Sha3_512 => "sha3-512".to_string(),
+        HashAlgorithm::Blake2b => "blake2b".to_string(),
+        HashAlgorithm::Blake3 => "blake3".to_string(),
+        HashAlgorithm::Keccak256 => "keccak-256".to_string(),
+    }
+}
+
+/// Constant-time byte comparison to avoid leaking mismatch position via timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// The result of checking one digest against recomputed artifact bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestCheck {
+    /// Algorithm used for the check.
+    pub algorithm: HashAlgorithm,
+    /// The digest recorded in the attestation.
+    pub expected: String,
+    /// The digest actually computed from the artifact bytes.
+    pub computed: String,
+    /// Whether they matched.
+    pub matches: bool,
+}
+
+/// Fetches the raw bytes backing a named dataset/artifact so they can be
+/// re-hashed and checked against a recorded digest.
+pub trait ArtifactFetcher {
+    /// Open a reader over the bytes for `name`.
+    fn fetch(&self, name: &str) -> Result<Box<dyn Read>>;
+}
+
+/// Outcome of verifying every subject and input of a `TransformAttestation`.
+#[derive(Debug, Clone, Default)]
+pub struct ArtifactVerification {
+    /// Names that failed their digest check, with the check that failed.
+    pub failures: Vec<(String, DigestCheck)>,
+    /// Names that could not be fetched or had no recorded digest to check.
+    pub skipped: Vec<String>,
+}
+
+impl ArtifactVerification {
+    /// Whether every subject and input verified successfully.
+    pub fn all_verified(&self) -> bool {
+        self.failures.is_empty() && self.skipped.is_empty()
+    }
+}
+
+impl TransformAttestation {
+    /// Re-hash every subject and input artifact via `fetcher` and report
+    /// exactly which digests failed (or were skipped because the artifact
+    /// couldn't be fetched).
+    pub fn verify_artifacts(&self, fetcher: &dyn ArtifactFetcher) -> ArtifactVerification {
+        let verifier = DigestVerifier::new();
+        let mut result = ArtifactVerification::default();
+
+        for input in &self.predicate.inputs {
+            check_one(&verifier, fetcher, &input.name, &input.digest, &mut result);
+        }
+
+        for subject in &self.subject {
+            check_one(&verifier, fetcher, &subject.name, &subject.digest, &mut result);
+        }
+
+        result
+    }
+}
+
+fn check_one(
+    verifier: &DigestVerifier,
+    fetcher: &dyn ArtifactFetcher,
+    name: &str,
+    digest: &Digest,
+    result: &mut ArtifactVerification,
+) {
+    let reader = match fetcher.fetch(name) {
+        Ok(r) => r,
+        Err(_) => {
+            result.skipped.push(name.to_string());
+            return;
+        }
+    };
+
+    match verifier.verify_digest(digest, HashAlgorithm::Sha256, reader) {
+        Ok(check) if check.matches => {}
+        Ok(check) => result.failures.push((name.to_string(), check)),
+        Err(_) => result.skipped.push(name.to_string()),
+    }
+}
+
+/// Verify a single `InputReference` against bytes from `reader`.
+pub fn verify_input(input: &InputReference, reader: impl Read) -> Result<DigestCheck> {
+    DigestVerifier::new().verify_digest(&input.digest, HashAlgorithm::Sha256, reader)
+}
+
+/// Verify a single `Subject` against bytes from `reader`.
+pub fn verify_subject(subject: &Subject, reader: impl Read) -> Result<DigestCheck> {
+    DigestVerifier::new().verify_digest(&subject.digest, HashAlgorithm::Sha256, reader)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::sha256_hex;
+
+    #[test]
+    fn test_verify_matches() {
+        let data = b"hello world";
+        let digest = Digest::new(sha256_hex(data));
+
+        let check = DigestVerifier::new()
+            .verify_digest(&digest, HashAlgorithm::Sha256, &data[..])
+            .unwrap();
+
+        assert!(check.matches);
+    }
+
+    #[test]
+    fn test_verify_mismatch() {
+        let digest = Digest::new("a".repeat(64));
+
+        let check = DigestVerifier::new()
+            .verify_digest(&digest, HashAlgorithm::Sha256, &b"different"[..])
+            .unwrap();
+
+        assert!(!check.matches);
+    }
+
+    #[test]
+    fn test_missing_algorithm_value_errors() {
+        let digest = Digest::new("a".repeat(64));
+
+        let result = DigestVerifier::new().verify_digest(&digest, HashAlgorithm::Sha512, &b"x"[..]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_artifact_verification_reports_failures_and_skips() {
+        struct FakeFetcher;
+        impl ArtifactFetcher for FakeFetcher {
+            fn fetch(&self, name: &str) -> Result<Box<dyn Read>> {
+                if name == "dataset:missing" {
+                    return Err(MakotoError::InvalidAttestation("not found".to_string()));
+                }
+                Ok(Box::new(&b"actual bytes"[..]))
+            }
+        }
+
+        let input = InputReference::new("dataset:missing", Digest::new("a".repeat(64)));
+        let subject = Subject::new("dataset:output", Digest::new("b".repeat(64)));
+
+        let attestation = TransformAttestation::builder()
+            .subject(subject)
+            .input(input)
+            .transform(crate::types::transform::TransformDefinition::new(
+                "https://makoto.dev/transforms/noop",
+                "Noop",
+            ))
+            .executor(crate::types::transform::Executor::new("exec-1"))
+            .build()
+            .unwrap();
+
+        let result = attestation.verify_artifacts(&FakeFetcher);
+
+        assert!(!result.all_verified());
+        assert_eq!(result.skipped, vec!["dataset:missing".to_string()]);
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].0, "dataset:output");
+    }
+}